@@ -0,0 +1,17 @@
+//! Minimal timing harness shared by the benches, which run with `harness = false`.
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Print the average time of `f` over a fixed number of iterations after a short warm-up.
+pub fn bench<R>(name: &str, mut f: impl FnMut() -> R) {
+    const ITERATIONS: u32 = 1000;
+    for _ in 0..ITERATIONS / 10 {
+        black_box(f());
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+    let elapsed: Duration = start.elapsed() / ITERATIONS;
+    println!("{name:<30} {elapsed:>12?}/iter");
+}
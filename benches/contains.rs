@@ -0,0 +1,23 @@
+//! `cargo bench --bench contains`
+mod common;
+
+use common::bench;
+use inter_val::{BoundType, Interval};
+use std::hint::black_box;
+
+fn main() {
+    let points: Vec<f64> = (0..10_000).map(|i| (i % 1000) as f64 * 0.01).collect();
+    let a = Interval::new(BoundType::Inclusive.at(0.0), BoundType::Exclusive.at(10.0));
+    let b = Interval::new(BoundType::Exclusive.at(2.5), BoundType::Inclusive.at(7.5));
+
+    bench("contains_all/per_point", || {
+        black_box(&points).iter().all(|t| a.contains(t))
+    });
+    bench("contains_all/batch", || a.contains_all(black_box(&points)));
+    bench("filter_contained/per_point", || {
+        black_box(&points).iter().filter(|t| b.contains(t)).count()
+    });
+    bench("filter_contained/batch", || {
+        b.filter_contained(black_box(&points)).count()
+    });
+}
@@ -0,0 +1,34 @@
+//! `cargo bench --bench interval_batch`
+mod common;
+
+use common::bench;
+use inter_val::{Inclusive, Interval, IntervalBatch};
+use std::hint::black_box;
+
+fn main() {
+    let items: Vec<Interval<f64>> = (0..10_000)
+        .map(|i| {
+            let a = (i * 7919 % 10_000) as f64 * 0.01;
+            Inclusive.between(a, a + (i % 17) as f64 * 0.1)
+        })
+        .collect();
+    let batch: IntervalBatch<f64> = items.iter().cloned().collect();
+    let query = Inclusive.between(40.0, 41.0);
+
+    bench("query_contains/vec", || {
+        (0..items.len())
+            .filter(|&i| items[i].contains(black_box(&50.0)))
+            .collect::<Vec<_>>()
+    });
+    bench("query_contains/batch", || {
+        batch.query_contains(black_box(&50.0))
+    });
+    bench("query_overlaps/vec", || {
+        (0..items.len())
+            .filter(|&i| items[i].overlaps(black_box(&query)))
+            .collect::<Vec<_>>()
+    });
+    bench("query_overlaps/batch", || {
+        batch.query_overlaps(black_box(&query))
+    });
+}
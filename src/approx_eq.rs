@@ -0,0 +1,141 @@
+use crate::{BoxN, Interval};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+/// Compare `inf` and `sup` approximately. The bound types must be identical.
+/// ```
+/// use inter_val::{BoundType, Inclusive, Exclusive};
+/// use approx::{assert_abs_diff_eq, assert_relative_ne, abs_diff_ne};
+/// let a = Inclusive.at(0.1 + 0.2).to(Exclusive.at(1.0));
+/// let b = Inclusive.at(0.3).to(Exclusive.at(1.0 - 1e-12));
+/// assert_ne!(a, b);
+/// assert_abs_diff_eq!(a, b, epsilon = 1e-9);
+/// assert!(abs_diff_ne!(a, Inclusive.at(0.3).to(Exclusive.at(1.1)), epsilon = 1e-9));
+/// assert_relative_ne!(a.closure(), Inclusive.between(0.3, 1.1));
+///
+/// // Bound types must be identical.
+/// let c = BoundType::Inclusive.at(0.0).to(BoundType::Exclusive.at(1.0));
+/// let d = BoundType::Inclusive.at(0.0).to(BoundType::Inclusive.at(1.0));
+/// assert!(abs_diff_ne!(c, d));
+/// ```
+impl<T: AbsDiffEq, L: PartialEq, R: PartialEq> AbsDiffEq for Interval<T, L, R>
+where
+    T::Epsilon: Clone,
+{
+    type Epsilon = T::Epsilon;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.left.bound_type == other.left.bound_type
+            && self.right.bound_type == other.right.bound_type
+            && self
+                .left
+                .limit
+                .abs_diff_eq(&other.left.limit, epsilon.clone())
+            && self.right.limit.abs_diff_eq(&other.right.limit, epsilon)
+    }
+}
+
+impl<T: RelativeEq, L: PartialEq, R: PartialEq> RelativeEq for Interval<T, L, R>
+where
+    T::Epsilon: Clone,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.left.bound_type == other.left.bound_type
+            && self.right.bound_type == other.right.bound_type
+            && self
+                .left
+                .limit
+                .relative_eq(&other.left.limit, epsilon.clone(), max_relative.clone())
+            && self
+                .right
+                .limit
+                .relative_eq(&other.right.limit, epsilon, max_relative)
+    }
+}
+
+impl<T: UlpsEq, L: PartialEq, R: PartialEq> UlpsEq for Interval<T, L, R>
+where
+    T::Epsilon: Clone,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.left.bound_type == other.left.bound_type
+            && self.right.bound_type == other.right.bound_type
+            && self
+                .left
+                .limit
+                .ulps_eq(&other.left.limit, epsilon.clone(), max_ulps)
+            && self
+                .right
+                .limit
+                .ulps_eq(&other.right.limit, epsilon, max_ulps)
+    }
+}
+
+/// Compare each axis approximately.
+/// ```
+/// use inter_val::Box2;
+/// use approx::{assert_abs_diff_eq, assert_abs_diff_ne};
+/// let a: Box2<f64> = Box2::between(&[0.1 + 0.2, 0.0], &[1.0, 1.0]);
+/// let b: Box2<f64> = Box2::between(&[0.3, 0.0], &[1.0, 1.0]);
+/// assert_abs_diff_eq!(a, b);
+/// assert_abs_diff_ne!(a, Box2::between(&[0.3, 0.0], &[1.0, 2.0]));
+/// ```
+impl<const N: usize, T: AbsDiffEq, L: PartialEq, R: PartialEq> AbsDiffEq for BoxN<N, T, L, R>
+where
+    T::Epsilon: Clone,
+{
+    type Epsilon = T::Epsilon;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon.clone()))
+    }
+}
+
+impl<const N: usize, T: RelativeEq, L: PartialEq, R: PartialEq> RelativeEq for BoxN<N, T, L, R>
+where
+    T::Epsilon: Clone,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon.clone(), max_relative.clone()))
+    }
+}
+
+impl<const N: usize, T: UlpsEq, L: PartialEq, R: PartialEq> UlpsEq for BoxN<N, T, L, R>
+where
+    T::Epsilon: Clone,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon.clone(), max_ulps))
+    }
+}
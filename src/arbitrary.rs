@@ -0,0 +1,63 @@
+use crate::bound_type::{Left, Right};
+use crate::traits::BoundaryOf;
+use crate::{Bound, Interval};
+use alloc::boxed::Box;
+use quickcheck::{Arbitrary, Gen};
+
+/// Generate non-empty intervals of statically bounded types, e.g., `Interval<i32>` and `Interval<f64, Inclusive, Exclusive>`.
+/// Candidates which are empty or have a NaN endpoint are regenerated.
+/// Shrinking moves both endpoints toward zero keeping the interval non-empty, i.e., toward *[0, 0]* if both bounds are inclusive.
+/// ```
+/// use inter_val::{Interval, Inclusive, Exclusive};
+/// use quickcheck::quickcheck;
+///
+/// fn span_includes(a: Interval<i32>, b: Interval<i32>) -> bool {
+///     a.span(&b).includes(&a)
+/// }
+/// quickcheck(span_includes as fn(Interval<i32>, Interval<i32>) -> bool);
+///
+/// fn not_empty(a: Interval<f64, Inclusive, Exclusive>) -> bool {
+///     a.inf() < a.sup()
+/// }
+/// quickcheck(not_empty as fn(Interval<f64, Inclusive, Exclusive>) -> bool);
+/// ```
+impl<T, L, R> Arbitrary for Interval<T, L, R>
+where
+    T: Arbitrary + PartialOrd,
+    L: BoundaryOf<Left> + Default + 'static,
+    R: BoundaryOf<Right> + Default + 'static,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        loop {
+            let (a, b) = (T::arbitrary(g), T::arbitrary(g));
+            let (a, b) = if b < a { (b, a) } else { (a, b) };
+            if let Some(interval) = Self::bounded(a, b) {
+                return interval;
+            }
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            (self.inf().clone(), self.sup().clone())
+                .shrink()
+                .filter_map(|(a, b)| Self::bounded(a, b)),
+        )
+    }
+}
+
+impl<T: PartialOrd, L: BoundaryOf<Left> + Default, R: BoundaryOf<Right> + Default>
+    Interval<T, L, R>
+{
+    fn bounded(a: T, b: T) -> Option<Self> {
+        let left = Bound {
+            limit: a,
+            bound_type: L::default(),
+        };
+        let right = Bound {
+            limit: b,
+            bound_type: R::default(),
+        };
+        Self::try_new(left, right)
+    }
+}
@@ -0,0 +1,168 @@
+use crate::bound_type::{Left, Right};
+use crate::traits::{Boundary, BoundaryOf};
+use crate::{Bound, BoundType, Interval};
+
+/// Interval addition *[a, b] + [c, d] = [a + c, b + d]*.
+/// Each bound of the result is inclusive only if the corresponding bounds of both operands are inclusive.
+/// Panics if rounding makes the result empty, which can happen only for floats with an exclusive bound.
+/// ```
+/// use inter_val::{Interval, Inclusive, Exclusive, BoundType};
+/// let a = Inclusive.between(1, 2);    // [1, 2]
+/// let b = Inclusive.between(10, 20);  // [10, 20]
+/// assert_eq!(a + b, Inclusive.between(11, 22));
+///
+/// let a = Inclusive.at(0).to(Exclusive.at(1));    // [0, 1)
+/// let b = Inclusive.at(5).to(Exclusive.at(6));    // [5, 6)
+/// assert_eq!(a + b, Inclusive.at(5).to(Exclusive.at(7)));
+///
+/// let a = BoundType::Exclusive.at(0.0).to(BoundType::Inclusive.at(1.0));  // (0, 1]
+/// let b = BoundType::Inclusive.at(0.5).to(BoundType::Inclusive.at(1.0));  // [0.5, 1]
+/// assert_eq!(a + b, BoundType::Exclusive.at(0.5).to(BoundType::Inclusive.at(2.0)));
+/// ```
+impl<T, L, R> core::ops::Add for Interval<T, L, R>
+where
+    T: PartialOrd + core::ops::Add<Output = T>,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+{
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        let left = Bound {
+            limit: self.left.0.limit + rhs.left.0.limit,
+            bound_type: self.left.0.bound_type.combine(&rhs.left.0.bound_type),
+        };
+        let right = Bound {
+            limit: self.right.0.limit + rhs.right.0.limit,
+            bound_type: self.right.0.bound_type.combine(&rhs.right.0.bound_type),
+        };
+        Self::new(left, right)
+    }
+}
+
+/// Interval subtraction *[a, b] - [c, d] = [a - d, b - c]*.
+/// Since *d* and *c* contribute to the left and right bounds respectively, the bound types of `rhs` are flipped, i.e., `Interval<T, R, L>`.
+/// Each bound of the result is inclusive only if the contributing bounds of both operands are inclusive.
+/// Panics if rounding makes the result empty, which can happen only for floats with an exclusive bound.
+/// ```
+/// use inter_val::{Interval, Inclusive, Exclusive, BoundType};
+/// let a = Inclusive.between(10, 20);  // [10, 20]
+/// let b = Inclusive.between(1, 2);    // [1, 2]
+/// assert_eq!(a - b, Inclusive.between(8, 19));
+///
+/// let a = Inclusive.at(0).to(Exclusive.at(10));   // [0, 10)
+/// let b = Exclusive.at(1).to(Inclusive.at(2));    // (1, 2]
+/// assert_eq!(a - b, Inclusive.at(-2).to(Exclusive.at(9)));
+///
+/// let a = BoundType::Inclusive.at(0).to(BoundType::Exclusive.at(10));   // [0, 10)
+/// let b = BoundType::Inclusive.at(1).to(BoundType::Exclusive.at(2));    // [1, 2)
+/// assert_eq!(a - b, BoundType::Exclusive.at(-2).to(BoundType::Exclusive.at(9)));
+/// ```
+impl<T, L, R> core::ops::Sub<Interval<T, R, L>> for Interval<T, L, R>
+where
+    T: PartialOrd + core::ops::Sub<Output = T>,
+    L: BoundaryOf<Left> + BoundaryOf<Right>,
+    R: BoundaryOf<Right> + BoundaryOf<Left>,
+{
+    type Output = Self;
+    fn sub(self, rhs: Interval<T, R, L>) -> Self::Output {
+        let left = Bound {
+            limit: self.left.0.limit - rhs.right.0.limit,
+            bound_type: self.left.0.bound_type.combine(&rhs.right.0.bound_type),
+        };
+        let right = Bound {
+            limit: self.right.0.limit - rhs.left.0.limit,
+            bound_type: self.right.0.bound_type.combine(&rhs.left.0.bound_type),
+        };
+        Self::new(left, right)
+    }
+}
+
+/// Interval negation *-[a, b] = [-b, -a]*. The bound types are swapped, i.e., `Interval<T, R, L>`.
+/// ```
+/// use inter_val::{Interval, Inclusive, Exclusive};
+/// let a = Inclusive.at(-2).to(Exclusive.at(3));   // [-2, 3)
+/// assert_eq!(-a, Exclusive.at(-3).to(Inclusive.at(2)));
+/// assert_eq!(-(-a), a);
+/// ```
+impl<T: core::ops::Neg<Output = T>, L, R> core::ops::Neg for Interval<T, L, R> {
+    type Output = Interval<T, R, L>;
+    fn neg(self) -> Self::Output {
+        self.map_monotonic_decreasing(T::neg)
+    }
+}
+
+/// Product of two endpoints. It is attained, i.e., inclusive, if both are inclusive or either is an inclusive zero.
+fn endpoint_product<T: num::Num + Copy>(a: (T, BoundType), b: (T, BoundType)) -> (T, BoundType) {
+    let attained = |(t, bound_type): (T, BoundType)| bound_type.is_inclusive() && t.is_zero();
+    let bound_type = if attained(a) || attained(b) {
+        BoundType::Inclusive
+    } else {
+        a.1.combine(&b.1)
+    };
+    (a.0 * b.0, bound_type)
+}
+
+/// Interval multiplication *[a, b] * [c, d] = [min(ac, ad, bc, bd), max(ac, ad, bc, bd)]*.
+/// The bound types of the result depend on which endpoint products are the extrema,
+/// so the result is `Interval<T, BoundType>` whatever the bound types of the operands are.
+/// If the extremum is attained by multiple products, it is inclusive if any of them is inclusive.
+/// ```
+/// use inter_val::{Interval, Inclusive, Exclusive, BoundType};
+/// let a = Inclusive.between(-2, 3);   // [-2, 3]
+/// let b = Inclusive.between(-1, 4);   // [-1, 4]
+/// assert_eq!(a * b, Interval::from(Inclusive.between(-8, 12)));
+///
+/// let a = Inclusive.at(2).to(Exclusive.at(3));    // [2, 3)
+/// let b = Inclusive.at(4).to(Exclusive.at(5));    // [4, 5)
+/// assert_eq!(a * b, BoundType::Inclusive.at(8).to(BoundType::Exclusive.at(15)));
+///
+/// let a = Inclusive.at(-3).to(Exclusive.at(-2));  // [-3, -2)
+/// assert_eq!(a * b, Interval::from(Exclusive.between(-15, -8)));
+///
+/// let a = Inclusive.at(-3).to(Exclusive.at(2));   // [-3, 2)
+/// assert_eq!(a * b, Interval::from(Exclusive.between(-15, 10)));
+///
+/// let a = Inclusive.at(-3).to(Exclusive.at(-2));  // [-3, -2)
+/// let b = Inclusive.at(-5).to(Exclusive.at(-4));  // [-5, -4)
+/// assert_eq!(a * b, BoundType::Exclusive.at(8).to(BoundType::Inclusive.at(15)));
+///
+/// // Zero is attained if it is an inclusive endpoint.
+/// let a = BoundType::Inclusive.at(0).to(BoundType::Exclusive.at(1));    // [0, 1)
+/// let b = BoundType::Exclusive.at(2).to(BoundType::Exclusive.at(3));    // (2, 3)
+/// assert_eq!(a * b, BoundType::Inclusive.at(0).to(BoundType::Exclusive.at(3)));
+/// ```
+impl<T, L, R> core::ops::Mul for Interval<T, L, R>
+where
+    T: num::Num + PartialOrd + Copy,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+{
+    type Output = Interval<T, BoundType>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let endpoints = |i: &Self| {
+            [
+                (*i.inf(), i.left.bound_type.to_bound_type()),
+                (*i.sup(), i.right.bound_type.to_bound_type()),
+            ]
+        };
+        let (a, b) = (endpoints(&self), endpoints(&rhs));
+        let products = [
+            endpoint_product(a[0], b[0]),
+            endpoint_product(a[0], b[1]),
+            endpoint_product(a[1], b[0]),
+            endpoint_product(a[1], b[1]),
+        ];
+        let extremum = |better: fn(&T, &T) -> bool| {
+            products[1..].iter().fold(products[0], |acc, &p| {
+                if better(&p.0, &acc.0) || (p.0 == acc.0 && p.1.is_inclusive()) {
+                    p
+                } else {
+                    acc
+                }
+            })
+        };
+        let min = extremum(|a, b| a < b);
+        let max = extremum(|a, b| a > b);
+        Interval::new(min.1.at(min.0), max.1.at(max.0))
+    }
+}
@@ -1,5 +1,6 @@
 use crate::traits::{Flip, IntoGeneral};
 
+#[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Bound<T, B> {
     pub limit: T,
@@ -27,12 +28,27 @@ impl<T, B: Flip> Flip for Bound<T, B> {
 }
 
 impl<T, B> Bound<T, B> {
+    /// Usable in `const` context, as well as `Inclusive.at()` and `Exclusive.at()`.
+    /// ```
+    /// use inter_val::{Bound, Inclusive};
+    /// const ORIGIN: Bound<i32, Inclusive> = Bound::new(0, Inclusive);
+    /// assert_eq!(ORIGIN, Inclusive.at(0));
+    /// ```
+    pub const fn new(limit: T, bound_type: B) -> Self {
+        Self { limit, bound_type }
+    }
     pub fn cast<U: From<T>>(self) -> Bound<U, B> {
         Bound {
             limit: self.limit.into(),
             bound_type: self.bound_type,
         }
     }
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Bound<U, B> {
+        Bound {
+            limit: f(self.limit),
+            bound_type: self.bound_type,
+        }
+    }
 }
 impl<T: num::NumCast, B> Bound<T, B> {
     pub fn try_cast<U: num::NumCast>(self) -> Option<Bound<U, B>> {
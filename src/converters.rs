@@ -81,3 +81,70 @@ impl<T: PartialOrd + Clone> From<T> for Interval<T, Inclusive> {
         Self::new(t.clone().into(), t.into())
     }
 }
+
+/// `Interval<T, BoundType>` resolved into one of the four statically typed intervals. See [`Interval::to_static`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaticInterval<T> {
+    /// *[a, b]*
+    Closed(Interval<T, Inclusive>),
+    /// *(a, b)*
+    Open(Interval<T, Exclusive>),
+    /// *[a, b)*
+    RightHalfOpen(Interval<T, Inclusive, Exclusive>),
+    /// *(a, b]*
+    LeftHalfOpen(Interval<T, Exclusive, Inclusive>),
+}
+
+impl<T: PartialOrd> StaticInterval<T> {
+    pub fn contains(&self, t: &T) -> bool {
+        match self {
+            Self::Closed(i) => i.contains(t),
+            Self::Open(i) => i.contains(t),
+            Self::RightHalfOpen(i) => i.contains(t),
+            Self::LeftHalfOpen(i) => i.contains(t),
+        }
+    }
+}
+
+impl<T> From<StaticInterval<T>> for Interval<T, BoundType> {
+    fn from(i: StaticInterval<T>) -> Self {
+        match i {
+            StaticInterval::Closed(i) => i.into(),
+            StaticInterval::Open(i) => i.into(),
+            StaticInterval::RightHalfOpen(i) => i.into(),
+            StaticInterval::LeftHalfOpen(i) => i.into(),
+        }
+    }
+}
+
+impl<T: Clone> Interval<T, BoundType> {
+    /// Resolve the runtime bound types once, so that a loop in each match arm uses the monomorphized `contains()` without branching on them.
+    /// ```
+    /// use inter_val::{BoundType, Interval, StaticInterval};
+    /// let a = BoundType::Inclusive.at(0).to(BoundType::Exclusive.at(10));
+    /// let points = [-1, 0, 5, 10];
+    /// let count = match a.to_static() {
+    ///     StaticInterval::RightHalfOpen(i) => points.iter().filter(|t| i.contains(t)).count(),
+    ///     other => points.iter().filter(|t| other.contains(t)).count(),
+    /// };
+    /// assert_eq!(count, 2);
+    /// assert_eq!(Interval::from(a.to_static()), a);
+    /// ```
+    pub fn to_static(&self) -> StaticInterval<T> {
+        let (left, right) = (self.left.limit.clone(), self.right.limit.clone());
+        match (self.left.bound_type, self.right.bound_type) {
+            (BoundType::Inclusive, BoundType::Inclusive) => StaticInterval::Closed(
+                Interval::new_unchecked(Inclusive.at(left), Inclusive.at(right)),
+            ),
+            (BoundType::Exclusive, BoundType::Exclusive) => StaticInterval::Open(
+                Interval::new_unchecked(Exclusive.at(left), Exclusive.at(right)),
+            ),
+            (BoundType::Inclusive, BoundType::Exclusive) => StaticInterval::RightHalfOpen(
+                Interval::new_unchecked(Inclusive.at(left), Exclusive.at(right)),
+            ),
+            (BoundType::Exclusive, BoundType::Inclusive) => StaticInterval::LeftHalfOpen(
+                Interval::new_unchecked(Exclusive.at(left), Inclusive.at(right)),
+            ),
+        }
+    }
+}
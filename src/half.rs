@@ -1,22 +1,23 @@
 use crate::{
     bound_type::{Left, Right},
     traits::{BoundaryOf, Flip, IntoGeneral},
-    Bound, BoundType, Exclusive, Inclusive,
+    Bound, BoundType, Exclusive, Inclusive, Interval,
 };
 
+#[repr(C)]
 #[derive(Debug, Clone, Copy)]
-pub struct HalfBounded<T, B, LR>(pub(crate) Bound<T, B>, std::marker::PhantomData<LR>);
+pub struct HalfBounded<T, B, LR>(pub(crate) Bound<T, B>, core::marker::PhantomData<LR>);
 
 pub type LeftBounded<T, B> = HalfBounded<T, B, Left>;
 pub type RightBounded<T, B> = HalfBounded<T, B, Right>;
 
-impl<T, B, LR> std::ops::Deref for HalfBounded<T, B, LR> {
+impl<T, B, LR> core::ops::Deref for HalfBounded<T, B, LR> {
     type Target = Bound<T, B>;
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
-impl<T, B, LR> std::ops::DerefMut for HalfBounded<T, B, LR> {
+impl<T, B, LR> core::ops::DerefMut for HalfBounded<T, B, LR> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
@@ -39,7 +40,7 @@ mod ordering {
         }
     }
     impl<T: PartialOrd, B: BoundaryOf<LR>, LR> PartialOrd for HalfBounded<T, B, LR> {
-        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
             self.ordering_key().partial_cmp(&other.ordering_key())
         }
     }
@@ -47,25 +48,28 @@ mod ordering {
 
 impl<T, B, LR> From<Bound<T, B>> for HalfBounded<T, B, LR> {
     fn from(b: Bound<T, B>) -> Self {
-        HalfBounded(b, std::marker::PhantomData)
+        Self::from_bound(b)
     }
 }
 
 impl<T, B: IntoGeneral, LR> IntoGeneral for HalfBounded<T, B, LR> {
     type General = HalfBounded<T, B::General, LR>;
     fn into_general(self) -> Self::General {
-        HalfBounded(self.0.into_general(), std::marker::PhantomData)
+        HalfBounded(self.0.into_general(), core::marker::PhantomData)
     }
 }
 
 impl<T, B: Flip, LR: Flip> Flip for HalfBounded<T, B, LR> {
     type Flip = HalfBounded<T, B::Flip, LR::Flip>;
     fn flip(self) -> Self::Flip {
-        HalfBounded(self.0.flip(), std::marker::PhantomData)
+        HalfBounded(self.0.flip(), core::marker::PhantomData)
     }
 }
 
 impl<T, B, LR> HalfBounded<T, B, LR> {
+    pub(crate) const fn from_bound(b: Bound<T, B>) -> Self {
+        HalfBounded(b, core::marker::PhantomData)
+    }
     pub fn cast<U: From<T>>(self) -> HalfBounded<U, B, LR> {
         self.0.cast().into()
     }
@@ -114,6 +118,17 @@ impl<T: PartialOrd, B: BoundaryOf<Left>> LeftBounded<T, B> {
         self.min(other)
     }
 
+    /// Intersection with a bounded interval. See [`Bound::ray_right`].
+    pub fn intersect_interval<R: BoundaryOf<Right>>(
+        &self,
+        other: &Interval<T, B, R>,
+    ) -> Option<Interval<T, B, R>>
+    where
+        T: Clone,
+    {
+        Interval::new_(self.intersection(&other.left).clone(), other.right.clone())
+    }
+
     pub fn hull(self, t: T) -> Self {
         Bound {
             limit: partial_min(self.0.limit, t),
@@ -124,7 +139,7 @@ impl<T: PartialOrd, B: BoundaryOf<Left>> LeftBounded<T, B> {
 
     pub fn dilate(self, delta: T) -> Self
     where
-        T: std::ops::Sub<Output = T>,
+        T: core::ops::Sub<Output = T>,
     {
         Bound {
             limit: self.0.limit - delta,
@@ -156,13 +171,13 @@ impl<T: PartialOrd, B: BoundaryOf<Left>> LeftBounded<T, B> {
     pub fn step_by(&self, step: T) -> impl Iterator<Item = T>
     where
         T: Clone,
-        for<'a> T: std::ops::AddAssign<&'a T>,
+        for<'a> T: core::ops::AddAssign<&'a T>,
     {
         let mut t = self.limit.clone();
         if self.bound_type == BoundType::Exclusive {
             t += &step;
         };
-        std::iter::from_fn(move || {
+        core::iter::from_fn(move || {
             let r = t.clone();
             t += &step;
             Some(r)
@@ -184,6 +199,17 @@ impl<T: PartialOrd, B: BoundaryOf<Right>> RightBounded<T, B> {
         self.max(other)
     }
 
+    /// Intersection with a bounded interval. See [`Bound::ray_left`].
+    pub fn intersect_interval<L: BoundaryOf<Left>>(
+        &self,
+        other: &Interval<T, L, B>,
+    ) -> Option<Interval<T, L, B>>
+    where
+        T: Clone,
+    {
+        Interval::new_(other.left.clone(), self.intersection(&other.right).clone())
+    }
+
     pub fn hull(self, t: T) -> Self {
         Bound {
             limit: partial_max(self.0.limit, t),
@@ -194,7 +220,7 @@ impl<T: PartialOrd, B: BoundaryOf<Right>> RightBounded<T, B> {
 
     pub fn dilate(self, delta: T) -> Self
     where
-        T: std::ops::Add<Output = T>,
+        T: core::ops::Add<Output = T>,
     {
         Bound {
             limit: self.0.limit + delta,
@@ -226,13 +252,13 @@ impl<T: PartialOrd, B: BoundaryOf<Right>> RightBounded<T, B> {
     pub fn step_rev_by(&self, step: T) -> impl Iterator<Item = T>
     where
         T: Clone,
-        for<'a> T: std::ops::SubAssign<&'a T>,
+        for<'a> T: core::ops::SubAssign<&'a T>,
     {
         let mut t = self.limit.clone();
         if self.bound_type == BoundType::Exclusive {
             t -= &step;
         };
-        std::iter::from_fn(move || {
+        core::iter::from_fn(move || {
             let r = t.clone();
             t -= &step;
             Some(r)
@@ -1,5 +1,5 @@
 use crate::{Exclusive, Inclusive, LeftBounded, RightBounded};
-use std::ops::{Bound, RangeBounds};
+use core::ops::{Bound, RangeBounds};
 
 impl<T: PartialOrd> RangeBounds<T> for LeftBounded<T, Inclusive> {
     fn start_bound(&self) -> Bound<&T> {
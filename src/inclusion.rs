@@ -1,4 +1,4 @@
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 use crate::traits::{Boundary, BoundaryOf, Flip, IntoGeneral};
 
@@ -37,12 +37,12 @@ mod ordering {
     macro_rules! impl_ord {
         (($lhs:ident, $rhs:ident): $type:ty => $body:expr) => {
             impl PartialOrd for $type {
-                fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
                     Some(self.cmp(other))
                 }
             }
             impl Ord for $type {
-                fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                fn cmp(&self, other: &Self) -> core::cmp::Ordering {
                     let $lhs = self;
                     let $rhs = other;
                     $body
@@ -51,21 +51,21 @@ mod ordering {
         };
     }
 
-    impl_ord!((_lhs, _rhs): SideInclusion<Inclusive, Left> => std::cmp::Ordering::Equal);
-    impl_ord!((_lhs, _rhs): SideInclusion<Exclusive, Left> => std::cmp::Ordering::Equal);
-    impl_ord!((_lhs, _rhs): SideInclusion<Inclusive, Right> => std::cmp::Ordering::Equal);
-    impl_ord!((_lhs, _rhs): SideInclusion<Exclusive, Right> => std::cmp::Ordering::Equal);
+    impl_ord!((_lhs, _rhs): SideInclusion<Inclusive, Left> => core::cmp::Ordering::Equal);
+    impl_ord!((_lhs, _rhs): SideInclusion<Exclusive, Left> => core::cmp::Ordering::Equal);
+    impl_ord!((_lhs, _rhs): SideInclusion<Inclusive, Right> => core::cmp::Ordering::Equal);
+    impl_ord!((_lhs, _rhs): SideInclusion<Exclusive, Right> => core::cmp::Ordering::Equal);
     impl_ord!((lhs, rhs): SideInclusion<BoundType, Left> => match (lhs.0, rhs.0) {
-        (BoundType::Inclusive, BoundType::Inclusive) => std::cmp::Ordering::Equal,
-        (BoundType::Inclusive, BoundType::Exclusive) => std::cmp::Ordering::Less,
-        (BoundType::Exclusive, BoundType::Inclusive) => std::cmp::Ordering::Greater,
-        (BoundType::Exclusive, BoundType::Exclusive) => std::cmp::Ordering::Equal,
+        (BoundType::Inclusive, BoundType::Inclusive) => core::cmp::Ordering::Equal,
+        (BoundType::Inclusive, BoundType::Exclusive) => core::cmp::Ordering::Less,
+        (BoundType::Exclusive, BoundType::Inclusive) => core::cmp::Ordering::Greater,
+        (BoundType::Exclusive, BoundType::Exclusive) => core::cmp::Ordering::Equal,
     });
     impl_ord!((lhs, rhs): SideInclusion<BoundType, Right> => match (lhs.0, rhs.0) {
-        (BoundType::Inclusive, BoundType::Inclusive) => std::cmp::Ordering::Equal,
-        (BoundType::Inclusive, BoundType::Exclusive) => std::cmp::Ordering::Greater,
-        (BoundType::Exclusive, BoundType::Inclusive) => std::cmp::Ordering::Less,
-        (BoundType::Exclusive, BoundType::Exclusive) => std::cmp::Ordering::Equal,
+        (BoundType::Inclusive, BoundType::Inclusive) => core::cmp::Ordering::Equal,
+        (BoundType::Inclusive, BoundType::Exclusive) => core::cmp::Ordering::Greater,
+        (BoundType::Exclusive, BoundType::Inclusive) => core::cmp::Ordering::Less,
+        (BoundType::Exclusive, BoundType::Exclusive) => core::cmp::Ordering::Equal,
     });
 }
 
@@ -0,0 +1,188 @@
+use crate::bound_type::{Left, Right};
+use crate::traits::BoundaryOf;
+use crate::{Inclusive, Interval};
+use alloc::vec::Vec;
+
+const BITS: i128 = u64::BITS as i128;
+
+/// Set of integers backed by a growable bitset, i.e., one bit per integer between the smallest and the largest inserted.
+/// Membership is *O(1)* rather than the *O(log n)* of [`IntervalSet`](crate::IntervalSet), at the cost of memory proportional to the covered range,
+/// so this suits dense domains like pixel rows or time slots.
+/// Even two integers far apart like `insert(0); insert(i64::MAX)` allocate a bit for every integer between them.
+/// The integers must fit in `i128`, i.e., every primitive integer but `u128` above `i128::MAX`.
+/// ```
+/// use inter_val::{Inclusive, Exclusive, IntIntervalSet};
+/// let mut a = IntIntervalSet::new();
+/// a.insert_interval(Inclusive.between(0, 5));
+/// a.insert_interval(Inclusive.at(-3).to(Exclusive.at(0)));
+/// a.insert_interval(Inclusive.between(10, 12));
+/// assert!(a.contains(&-3));
+/// assert!(!a.contains(&6));
+/// assert_eq!(a.len(), 12);
+/// assert_eq!(
+///     a.iter_intervals().collect::<Vec<_>>(),
+///     vec![Inclusive.between(-3, 5), Inclusive.between(10, 12)]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntIntervalSet<T> {
+    origin: i128,
+    words: Vec<u64>,
+    _marker: core::marker::PhantomData<T>,
+}
+
+impl<T> Default for IntIntervalSet<T> {
+    fn default() -> Self {
+        Self {
+            origin: 0,
+            words: Vec::new(),
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<T> IntIntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of the integers in the set.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    fn ensure(&mut self, first: i128, last: i128) {
+        let origin = first.div_euclid(BITS) * BITS;
+        if self.words.is_empty() {
+            self.origin = origin;
+        } else if origin < self.origin {
+            let n = ((self.origin - origin) / BITS) as usize;
+            self.words.splice(0..0, core::iter::repeat_n(0, n));
+            self.origin = origin;
+        }
+        let len = ((last - self.origin) / BITS + 1) as usize;
+        if self.words.len() < len {
+            self.words.resize(len, 0);
+        }
+    }
+
+    fn set_bits(&mut self, first: usize, last: usize) {
+        let (first_word, last_word) = (first / 64, last / 64);
+        for (i, word) in self.words[first_word..=last_word].iter_mut().enumerate() {
+            let lo = if i == 0 { first % 64 } else { 0 };
+            let hi = if first_word + i == last_word {
+                last % 64
+            } else {
+                63
+            };
+            *word |= (u64::MAX >> (63 - hi)) & (u64::MAX << lo);
+        }
+    }
+
+    /// Index of the first bit at or after `from` which equals `value`.
+    /// Bits beyond the end are regarded as zero.
+    fn find_bit(&self, from: usize, value: bool) -> Option<usize> {
+        let load = |i: usize| {
+            let word = self.words.get(i).copied().unwrap_or(0);
+            if value {
+                word
+            } else {
+                !word
+            }
+        };
+        let mut i = from / 64;
+        let mut word = load(i) & (u64::MAX << (from % 64));
+        while word == 0 {
+            i += 1;
+            if i >= self.words.len() {
+                return (!value).then_some(i * 64);
+            }
+            word = load(i);
+        }
+        Some(i * 64 + word.trailing_zeros() as usize)
+    }
+}
+
+impl<T: num::PrimInt> IntIntervalSet<T> {
+    /// Insert all the integers in `item`. Nothing is inserted if `item` contains no integer, e.g., *(0, 1)*.
+    ///
+    /// # Panics
+    /// ```should_panic
+    /// # use inter_val::{Inclusive, IntIntervalSet};
+    /// let mut a = IntIntervalSet::new();
+    /// a.insert_interval(Inclusive.between(0, u128::MAX)); // u128::MAX doesn't fit in i128.
+    /// ```
+    pub fn insert_interval<L: BoundaryOf<Left>, R: BoundaryOf<Right>>(
+        &mut self,
+        item: Interval<T, L, R>,
+    ) {
+        let (first, last) = item.integer_bounds();
+        if first > last {
+            return;
+        }
+        let to_i128 = |t: T| {
+            t.to_i128()
+                .expect("IntIntervalSet supports integers within i128 only.")
+        };
+        let (first, last) = (to_i128(first), to_i128(last));
+        self.ensure(first, last);
+        self.set_bits(
+            (first - self.origin) as usize,
+            (last - self.origin) as usize,
+        );
+    }
+
+    pub fn insert(&mut self, t: T) {
+        self.insert_interval(Interval::<T>::new_unchecked(
+            Inclusive.at(t),
+            Inclusive.at(t),
+        ));
+    }
+
+    pub fn contains(&self, t: &T) -> bool {
+        let Some(index) = t.to_i128().map(|t| t - self.origin) else {
+            return false;
+        };
+        (0..self.words.len() as i128 * BITS).contains(&index)
+            && self.words[index as usize / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Maximal runs of consecutive integers in ascending order.
+    pub fn iter_intervals(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        let mut next = 0;
+        core::iter::from_fn(move || {
+            let first = self.find_bit(next, true)?;
+            let last = self.find_bit(first, false)? - 1;
+            next = last + 1;
+            let to_t = |i: usize| T::from(self.origin + i as i128).unwrap();
+            Some(Interval::new_unchecked(
+                Inclusive.at(to_t(first)),
+                Inclusive.at(to_t(last)),
+            ))
+        })
+    }
+}
+
+impl<T: num::PrimInt, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Extend<Interval<T, L, R>>
+    for IntIntervalSet<T>
+{
+    fn extend<I: IntoIterator<Item = Interval<T, L, R>>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert_interval(item);
+        }
+    }
+}
+
+impl<T: num::PrimInt, L: BoundaryOf<Left>, R: BoundaryOf<Right>> FromIterator<Interval<T, L, R>>
+    for IntIntervalSet<T>
+{
+    fn from_iter<I: IntoIterator<Item = Interval<T, L, R>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
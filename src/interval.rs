@@ -1,6 +1,11 @@
 use crate::bound_type::{Left, Right};
-use crate::traits::{BoundaryOf, Flip, IntoGeneral};
-use crate::{Bound, Exclusive, Inclusive, LeftBounded, RightBounded};
+use crate::traits::{Boundary, BoundaryOf, Containee, Flip, IntoGeneral};
+use crate::{
+    Bound, BoundType, Exclusive, HalfBounded, Inclusive, LeftBounded, Nullable, RightBounded,
+    ValidationError,
+};
+use alloc::vec;
+use alloc::vec::Vec;
 
 /// Return type of `Interval::union()`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -15,7 +20,7 @@ impl<T, L: Flip, R: Flip> IntervalUnion<T, L, R> {
 }
 impl<T, L: Flip, R: Flip> IntoIterator for IntervalUnion<T, L, R> {
     type Item = Interval<T, L, R>;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
     fn into_iter(self) -> Self::IntoIter {
         if let Some(gap) = self.gap {
             let first = Interval {
@@ -47,13 +52,126 @@ impl<T, L: Flip<Flip = R>, R: Flip<Flip = L>> IntervalDifference<T, L, R> {
 impl<T, L: Flip<Flip = R>, R: Flip<Flip = L>> IntoIterator for IntervalDifference<T, L, R> {
     type Item = Interval<T, L, R>;
     type IntoIter =
-        std::iter::Chain<std::option::IntoIter<Self::Item>, std::option::IntoIter<Self::Item>>;
+        core::iter::Chain<core::option::IntoIter<Self::Item>, core::option::IntoIter<Self::Item>>;
     fn into_iter(self) -> Self::IntoIter {
         self.lower.into_iter().chain(self.upper)
     }
 }
 
-fn is_valid_interval<T, L, R>(left: &LeftBounded<T, L>, right: &RightBounded<T, R>) -> bool
+/// Return type of `Interval::summary()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntervalRelationSummary<T> {
+    /// `self.overlaps(other)`
+    pub overlaps: bool,
+    /// `self.includes(other)`
+    pub includes: bool,
+    /// `other.includes(self)`
+    pub included_by: bool,
+    /// They don't overlap, but there is no gap between them.
+    pub touches: bool,
+    /// Measure of `self.gap(other)`, or zero if there is no gap.
+    pub gap_measure: T,
+    /// Measure of `self.intersection(other)`, or zero if they don't overlap.
+    pub overlap_measure: T,
+}
+
+/// Policy for `Interval::coalesce_with_policy()` to handle a single point gap between two exclusive bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundPolicy {
+    /// Bridge the gap, e.g., *[0, 5)* and *(5, 10]* are merged into *[0, 10]*.
+    Inclusive,
+    /// Leave the gap, e.g., *[0, 5)* and *(5, 10]* are not merged.
+    Exclusive,
+}
+impl BoundPolicy {
+    fn bridges<T: PartialEq, L, R>(&self, gap: Option<Interval<T, L, R>>) -> bool {
+        match gap {
+            None => true,
+            Some(gap) => *self == Self::Inclusive && gap.left.limit == gap.right.limit,
+        }
+    }
+}
+
+/// Iterator over the integers in an interval. Return type of `Interval::into_iter()`.
+/// Like `RangeInclusive`, `ExactSizeIterator` is implemented only for the types whose span always fits in `usize`, i.e., `u8`, `i8`, `u16`, and `i16`.
+/// For the other types, `size_hint()` is exact unless the number of the integers exceeds `usize::MAX`.
+#[derive(Debug, Clone)]
+pub struct IntegerIter<T> {
+    front: T,
+    back: T,
+    exhausted: bool,
+}
+impl<T: num::Integer + num::CheckedSub + num::ToPrimitive + Clone> Iterator for IntegerIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        if self.exhausted {
+            return None;
+        }
+        let t = self.front.clone();
+        if self.front == self.back {
+            self.exhausted = true;
+        } else {
+            self.front = t.clone() + T::one();
+        }
+        Some(t)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = || {
+            // `back - front` overflows `T` for a signed span over half its range, e.g., `i32::MIN..=i32::MAX`.
+            let d = match self.back.checked_sub(&self.front) {
+                Some(d) => d.to_usize()?,
+                None => {
+                    let d = self.back.to_i128()?.checked_sub(self.front.to_i128()?)?;
+                    usize::try_from(d).ok()?
+                }
+            };
+            d.checked_add(1)
+        };
+        match (self.exhausted, remaining()) {
+            (true, _) => (0, Some(0)),
+            (false, Some(n)) => (n, Some(n)),
+            (false, None) => (0, None),
+        }
+    }
+}
+impl<T: num::Integer + num::CheckedSub + num::ToPrimitive + Clone> DoubleEndedIterator
+    for IntegerIter<T>
+{
+    fn next_back(&mut self) -> Option<T> {
+        if self.exhausted {
+            return None;
+        }
+        let t = self.back.clone();
+        if self.front == self.back {
+            self.exhausted = true;
+        } else {
+            self.back = t.clone() - T::one();
+        }
+        Some(t)
+    }
+}
+macro_rules! impl_exact_size_integer_iter {
+    ($($t:ty),*) => {$(
+        impl ExactSizeIterator for IntegerIter<$t> {}
+    )*};
+}
+impl_exact_size_integer_iter!(u8, i8, u16, i16);
+impl<T: num::Integer + num::CheckedSub + num::ToPrimitive + Clone> core::iter::FusedIterator
+    for IntegerIter<T>
+{
+}
+
+/// `all()` evaluating `f` for every item in a chunk without short-circuiting, which lets the compiler vectorize the loop.
+fn all_chunked<T>(items: &[T], f: impl Fn(&T) -> bool) -> bool {
+    items
+        .chunks(32)
+        .all(|chunk| chunk.iter().fold(true, |acc, t| acc & f(t)))
+}
+
+pub(crate) fn is_valid_interval<T, L, R>(
+    left: &LeftBounded<T, L>,
+    right: &RightBounded<T, R>,
+) -> bool
 where
     T: PartialOrd,
     L: BoundaryOf<Left>,
@@ -109,6 +227,7 @@ where
 /// |<---->| a.difference(&b)
 ///                                                |<- δ -+---- c.dilate(δ) ----+- δ ->|
 /// ```
+#[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Interval<T, L = Inclusive, R = L> {
     pub(crate) left: LeftBounded<T, L>,
@@ -116,6 +235,24 @@ pub struct Interval<T, L = Inclusive, R = L> {
 }
 
 impl<T, L, R> Interval<T, L, R> {
+    /// Create a new interval without checking that it is non-empty, which makes it usable in `const` context.
+    /// The caller must ensure that `left` is less than `right`, or equal to it if both are inclusive;
+    /// otherwise the result is an empty interval which the other methods assume never exists.
+    /// Being `const`, it cannot check this even in debug builds; prefer `new()` unless the validation cost matters.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// const HOURS: Interval<u32, Inclusive, Exclusive> =
+    ///     Interval::new_unchecked(Inclusive.at(0), Exclusive.at(24));
+    /// assert_eq!(HOURS, Interval::new(Inclusive.at(0), Exclusive.at(24)));
+    /// assert!(HOURS.contains(&23));
+    /// ```
+    pub const fn new_unchecked(left: Bound<T, L>, right: Bound<T, R>) -> Self {
+        Self {
+            left: HalfBounded::from_bound(left),
+            right: HalfBounded::from_bound(right),
+        }
+    }
+
     pub fn left(&self) -> &LeftBounded<T, L> {
         &self.left
     }
@@ -124,10 +261,43 @@ impl<T, L, R> Interval<T, L, R> {
     }
 }
 impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R> {
-    fn new_(left: LeftBounded<T, L>, right: RightBounded<T, R>) -> Option<Self> {
+    pub(crate) fn new_(left: LeftBounded<T, L>, right: RightBounded<T, R>) -> Option<Self> {
         is_valid_interval(&left, &right).then_some(Self { left, right })
     }
 
+    fn left_ordering(&self, other: &Self) -> core::cmp::Ordering {
+        self.left
+            .partial_cmp(&other.left)
+            .unwrap_or(core::cmp::Ordering::Equal)
+    }
+
+    /// Lexicographic order of the left bounds and then the right bounds, for `sort_by()` and the like.
+    /// Incomparable limits, e.g., `NaN`, are regarded as equal.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let mut items = vec![
+    ///     Inclusive.at(1).to(Exclusive.at(2)),
+    ///     Inclusive.at(0).to(Exclusive.at(5)),
+    ///     Inclusive.at(0).to(Exclusive.at(3)),
+    /// ];
+    /// items.sort_by(Interval::cmp_by_bounds);
+    /// assert_eq!(
+    ///     items,
+    ///     vec![
+    ///         Inclusive.at(0).to(Exclusive.at(3)),
+    ///         Inclusive.at(0).to(Exclusive.at(5)),
+    ///         Inclusive.at(1).to(Exclusive.at(2)),
+    ///     ]
+    /// );
+    /// ```
+    pub fn cmp_by_bounds(&self, other: &Self) -> core::cmp::Ordering {
+        self.left_ordering(other).then_with(|| {
+            self.right
+                .partial_cmp(&other.right)
+                .unwrap_or(core::cmp::Ordering::Equal)
+        })
+    }
+
     /// Try to create a new interval. Return `None` if the interval is empty.
     /// ```
     /// use std::any::{Any, TypeId};
@@ -251,6 +421,50 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         self.right.sup()
     }
 
+    /// Return true if `inf == sup`, which is possible only for a closed interval *[a, a]*.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// assert!(Interval::singleton(3).is_degenerate());    // [3, 3]
+    /// assert!(!Inclusive.between(3, 4).is_degenerate());  // [3, 4]
+    /// assert!(Inclusive.at(3).try_to(Exclusive.at(3)).is_none());   // [3, 3) can't be constructed.
+    /// ```
+    pub fn is_degenerate(&self) -> bool {
+        self.inf() == self.sup()
+    }
+
+    /// Runtime bound types of the left and right boundaries.
+    /// ```
+    /// use inter_val::{BoundType, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(3));
+    /// assert_eq!(a.bound_types(), (BoundType::Inclusive, BoundType::Exclusive));
+    /// ```
+    pub fn bound_types(&self) -> (BoundType, BoundType) {
+        (
+            self.left.bound_type.to_bound_type(),
+            self.right.bound_type.to_bound_type(),
+        )
+    }
+
+    /// Decompose into `(inf, left bound type, sup, right bound type)` regardless of the static bound types.
+    /// ```
+    /// use inter_val::{BoundType, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(3));
+    /// let text = match a.decompose() {
+    ///     (a, BoundType::Inclusive, b, BoundType::Inclusive) => format!("{a} <= x <= {b}"),
+    ///     (a, BoundType::Inclusive, b, BoundType::Exclusive) => format!("{a} <= x < {b}"),
+    ///     (a, BoundType::Exclusive, b, BoundType::Inclusive) => format!("{a} < x <= {b}"),
+    ///     (a, BoundType::Exclusive, b, BoundType::Exclusive) => format!("{a} < x < {b}"),
+    /// };
+    /// assert_eq!(text, "0 <= x < 3");
+    /// ```
+    pub fn decompose(&self) -> (T, BoundType, T, BoundType)
+    where
+        T: Clone,
+    {
+        let (l, r) = self.bound_types();
+        (self.inf().clone(), l, self.sup().clone(), r)
+    }
+
     pub fn closure(self) -> Interval<T, Inclusive> {
         Interval {
             left: self.left.closure(),
@@ -276,6 +490,179 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         self.left.contains(t) && self.right.contains(t)
     }
 
+    /// Whether all of `points` are contained. Equivalent to `points.iter().all(|t| self.contains(t))`,
+    /// but the bound types are examined once rather than per point.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(4).to(Exclusive.at(7));
+    /// assert!(a.contains_all(&[4, 5, 6]));
+    /// assert!(!a.contains_all(&[4, 5, 7]));
+    /// assert!(a.contains_all(&[]));
+    /// ```
+    pub fn contains_all(&self, points: &[T]) -> bool {
+        let (lo, hi) = (&self.left.limit, &self.right.limit);
+        match (
+            self.left.bound_type.is_inclusive(),
+            self.right.bound_type.is_inclusive(),
+        ) {
+            (true, true) => all_chunked(points, |t| (lo <= t) & (t <= hi)),
+            (true, false) => all_chunked(points, |t| (lo <= t) & (t < hi)),
+            (false, true) => all_chunked(points, |t| (lo < t) & (t <= hi)),
+            (false, false) => all_chunked(points, |t| (lo < t) & (t < hi)),
+        }
+    }
+
+    /// Iterate over the `points` contained in `self`, examining the bound types only once.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Exclusive.at(1.0).to(Inclusive.at(2.0));
+    /// let points = [0.5, 1.0, 1.5, 2.0, 2.5];
+    /// let inside: Vec<_> = a.filter_contained(&points).collect();
+    /// assert_eq!(inside, vec![&1.5, &2.0]);
+    /// ```
+    pub fn filter_contained<'a>(&'a self, points: &'a [T]) -> impl Iterator<Item = &'a T> + 'a {
+        let (lo, hi) = (&self.left.limit, &self.right.limit);
+        let (left_inclusive, right_inclusive) = (
+            self.left.bound_type.is_inclusive(),
+            self.right.bound_type.is_inclusive(),
+        );
+        points.iter().filter(move |t| {
+            (if left_inclusive { lo <= *t } else { lo < *t })
+                && (if right_inclusive { *t <= hi } else { *t < hi })
+        })
+    }
+
+    /// Whether `t` is in the interior, i.e., *inf < t < sup*, regardless of the bound types.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.between(4, 7);   // [4, 7]
+    /// assert!(a.contains(&4) && !a.contains_interior(&4));
+    /// assert!(a.contains_interior(&5));
+    /// ```
+    pub fn contains_interior(&self, t: &T) -> bool {
+        self.inf() < t && t < self.sup()
+    }
+
+    /// Check that all of `values` are contained in the interval and monotonically non-decreasing.
+    /// The error identifies the first offending value.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive, ValidationError};
+    /// let a = Inclusive.at(0).to(Exclusive.at(10));   // [0, 10)
+    /// assert_eq!(a.validate_monotonic_within(&[0, 2, 2, 9]), Ok(()));
+    /// assert_eq!(
+    ///     a.validate_monotonic_within(&[0, 2, 10, 11]),
+    ///     Err(ValidationError::OutOfInterval { index: 2 })
+    /// );
+    /// assert_eq!(
+    ///     a.validate_monotonic_within(&[0, 5, 3, 11]),
+    ///     Err(ValidationError::NotMonotonic { index: 2 })
+    /// );
+    /// ```
+    pub fn validate_monotonic_within(&self, values: &[T]) -> Result<(), ValidationError> {
+        for (index, t) in values.iter().enumerate() {
+            if !self.contains(t) {
+                return Err(ValidationError::OutOfInterval { index });
+            }
+            if index > 0 && *t < values[index - 1] {
+                return Err(ValidationError::NotMonotonic { index });
+            }
+        }
+        Ok(())
+    }
+
+    /// Nearest point of the closure of the interval to `t`.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(10));   // [0, 10)
+    /// assert_eq!(a.clamp(-5), 0);
+    /// assert_eq!(a.clamp(5), 5);
+    /// assert_eq!(a.clamp(15), 10);
+    /// ```
+    pub fn clamp(&self, t: T) -> T
+    where
+        T: Clone,
+    {
+        if t < *self.inf() {
+            self.inf().clone()
+        } else if *self.sup() < t {
+            self.sup().clone()
+        } else {
+            t
+        }
+    }
+
+    /// Distance from `t` to the closure of the interval, i.e., zero if `t` is in the closure.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(10));   // [0, 10)
+    /// assert_eq!(a.distance_to(&-5), 5);
+    /// assert_eq!(a.distance_to(&5), 0);
+    /// assert_eq!(a.distance_to(&15), 5);
+    /// ```
+    pub fn distance_to(&self, t: &T) -> T
+    where
+        T: Clone + num::Zero + core::ops::Sub<Output = T>,
+    {
+        if t < self.inf() {
+            self.inf().clone() - t.clone()
+        } else if self.sup() < t {
+            t.clone() - self.sup().clone()
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Snap `value` to the nearest point of the union of `set`.
+    /// `value` is returned as is if it is contained in the closure of some member or `set` is empty.
+    /// ```
+    /// use inter_val::{Interval, Inclusive};
+    /// let bands = [Inclusive.between(0, 10), Inclusive.between(20, 30), Inclusive.between(50, 60)];
+    /// assert_eq!(Interval::snap_value_to_set(5, &bands), 5);
+    /// assert_eq!(Interval::snap_value_to_set(13, &bands), 10);
+    /// assert_eq!(Interval::snap_value_to_set(17, &bands), 20);
+    /// assert_eq!(Interval::snap_value_to_set(99, &bands), 60);
+    /// ```
+    pub fn snap_value_to_set(value: T, set: &[Self]) -> T
+    where
+        T: Clone + num::Zero + core::ops::Sub<Output = T>,
+    {
+        set.iter()
+            .map(|item| (item.distance_to(&value), item))
+            .min_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal))
+            .map(|(_, item)| item.clamp(value.clone()))
+            .unwrap_or(value)
+    }
+
+    /// Sub-slice of `sorted` contained in the interval, found by binary search.
+    /// `sorted` must be sorted in ascending order.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let sorted = [1, 2, 3, 3, 5, 8, 8, 9];
+    /// let a = Exclusive.at(3).to(Inclusive.at(8));    // (3, 8]
+    /// assert_eq!(a.slice_in_sorted(&sorted), &[5, 8, 8]);
+    ///
+    /// let a = Inclusive.at(3).to(Exclusive.at(8));    // [3, 8)
+    /// assert_eq!(a.slice_in_sorted(&sorted), &[3, 3, 5]);
+    /// ```
+    pub fn slice_in_sorted<'a>(&self, sorted: &'a [T]) -> &'a [T] {
+        let begin = sorted.partition_point(|t| !self.left.contains(t));
+        let end = sorted.partition_point(|t| self.right.contains(t));
+        &sorted[begin..end.max(begin)]
+    }
+
+    /// Number of items of `sorted` contained in the interval, counted in *O(log n)*.
+    /// `sorted` must be sorted in ascending order.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let sorted = [1, 2, 3, 3, 5, 8, 8, 9];
+    /// assert_eq!(Exclusive.at(3).to(Exclusive.at(8)).count_in_sorted(&sorted), 1);
+    /// assert_eq!(Inclusive.at(3).to(Inclusive.at(8)).count_in_sorted(&sorted), 5);
+    /// assert_eq!(Exclusive.at(9).to(Inclusive.at(12)).count_in_sorted(&sorted), 0);
+    /// ```
+    pub fn count_in_sorted(&self, sorted: &[T]) -> usize {
+        self.slice_in_sorted(sorted).len()
+    }
+
     /// ```
     /// use inter_val::{Inclusive, Exclusive};
     /// let a = Inclusive.at(4).to(Exclusive.at(7));    // [4, 7)
@@ -289,9 +676,161 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
     /// ```
     pub fn dilate(self, delta: T) -> Self
     where
-        T: Clone + std::ops::Add<Output = T> + std::ops::Sub<Output = T>,
+        T: Clone + core::ops::Add<Output = T> + core::ops::Sub<Output = T>,
+    {
+        self.try_dilate(delta).unwrap()
+    }
+
+    /// Same as `dilate()` but return `None` if the result is empty instead of panicking.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Inclusive.at(4).to(Exclusive.at(7));    // [4, 7)
+    /// assert_eq!(a.try_dilate(-1), Some(Inclusive.at(5).to(Exclusive.at(6))));
+    /// assert_eq!(a.try_dilate(-2), None);
+    /// ```
+    pub fn try_dilate(self, delta: T) -> Option<Self>
+    where
+        T: Clone + core::ops::Add<Output = T> + core::ops::Sub<Output = T>,
+    {
+        Self::new_(self.left.dilate(delta.clone()), self.right.dilate(delta))
+    }
+
+    /// Move only the left endpoint outward by `delta`, i.e., inward if `delta` is negative.
+    /// Return `None` if the result is empty.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(3));    // [0, 3)
+    /// assert_eq!(a.dilate_left(2), Some(Inclusive.at(-2).to(Exclusive.at(3))));
+    /// assert_eq!(a.dilate_left(-3), None);
+    /// ```
+    pub fn dilate_left(self, delta: T) -> Option<Self>
+    where
+        T: core::ops::Sub<Output = T>,
+    {
+        Self::new_(self.left.dilate(delta), self.right)
+    }
+
+    /// Move only the right endpoint outward by `delta`, i.e., inward if `delta` is negative.
+    /// Return `None` if the result is empty.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(3));    // [0, 3)
+    /// assert_eq!(a.dilate_right(2), Some(Inclusive.at(0).to(Exclusive.at(5))));
+    /// assert_eq!(a.dilate_right(-2), Some(Inclusive.at(0).to(Exclusive.at(1))));
+    /// assert_eq!(a.dilate_right(-3), None);
+    /// ```
+    pub fn dilate_right(self, delta: T) -> Option<Self>
+    where
+        T: core::ops::Add<Output = T>,
+    {
+        Self::new_(self.left, self.right.dilate(delta))
+    }
+
+    /// Move the left and right endpoints outward by `left_delta` and `right_delta` respectively.
+    /// Return `None` if the result is empty.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(3));    // [0, 3)
+    /// assert_eq!(a.dilate_asym(1, 2), Some(Inclusive.at(-1).to(Exclusive.at(5))));
+    /// assert_eq!(a.dilate_asym(-2, -1), None);
+    /// ```
+    pub fn dilate_asym(self, left_delta: T, right_delta: T) -> Option<Self>
+    where
+        T: core::ops::Add<Output = T> + core::ops::Sub<Output = T>,
+    {
+        Self::new_(self.left.dilate(left_delta), self.right.dilate(right_delta))
+    }
+
+    /// Image of the interval by *x ↦ scale * x + offset*.
+    /// If `scale` is negative, the endpoints are swapped together with their bound types.
+    /// If `scale` is zero, the result is the single point *[offset, offset]*.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive, BoundType};
+    /// let a = Inclusive.between(0, 1);    // [0, 1]
+    /// assert_eq!(a.affine(-2, 5), Interval::from(Inclusive.between(3, 5)));
+    /// assert_eq!(a.affine(3, 1), Interval::from(Inclusive.between(1, 4)));
+    ///
+    /// let a = Inclusive.at(0.0).to(Exclusive.at(1.0));    // [0, 1)
+    /// assert_eq!(a.affine(2.0, 1.0), BoundType::Inclusive.at(1.0).to(BoundType::Exclusive.at(3.0)));
+    /// assert_eq!(a.affine(-2.0, 5.0), BoundType::Exclusive.at(3.0).to(BoundType::Inclusive.at(5.0)));
+    /// assert_eq!(a.affine(0.0, 5.0), Interval::from(Inclusive.between(5.0, 5.0)));
+    /// ```
+    pub fn affine(&self, scale: T, offset: T) -> Interval<T, BoundType>
+    where
+        T: num::Num + Copy,
+    {
+        let left = (*self.inf(), self.left.bound_type.to_bound_type());
+        let right = (*self.sup(), self.right.bound_type.to_bound_type());
+        let (left, right) = if scale.is_zero() {
+            (
+                (offset, BoundType::Inclusive),
+                (offset, BoundType::Inclusive),
+            )
+        } else if scale > T::zero() {
+            (left, right)
+        } else {
+            (right, left)
+        };
+        let f = |t: T| scale * t + offset;
+        Interval::new(left.1.at(f(left.0)), right.1.at(f(right.0)))
+    }
+
+    /// Interval addition *[a, b] + [c, d] = [a + c, b + d]* clamping the endpoints to the range of `T` instead of overflowing.
+    /// Each bound of the result is inclusive only if the corresponding bounds of both operands are inclusive.
+    /// Return `None` if the clamping makes the result empty, which can happen only when a bound is exclusive.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(i32::MAX - 10).to(Inclusive.at(i32::MAX - 1));
+    /// let b = Inclusive.at(5).to(Inclusive.at(20));
+    /// assert_eq!(a.saturating_add(&b), Some(Inclusive.at(i32::MAX - 5).to(Inclusive.at(i32::MAX))));
+    ///
+    /// let a = Inclusive.at(i32::MAX - 1).to(Exclusive.at(i32::MAX));
+    /// let b = Inclusive.at(5).to(Exclusive.at(10));
+    /// assert_eq!(a.saturating_add(&b), None); // [MAX, MAX) is empty.
+    /// ```
+    pub fn saturating_add(&self, other: &Self) -> Option<Self>
+    where
+        T: num::traits::SaturatingAdd,
+    {
+        let left = Bound {
+            limit: self.inf().saturating_add(other.inf()),
+            bound_type: self.left.bound_type.combine(&other.left.bound_type),
+        };
+        let right = Bound {
+            limit: self.sup().saturating_add(other.sup()),
+            bound_type: self.right.bound_type.combine(&other.right.bound_type),
+        };
+        Self::try_new(left, right)
+    }
+
+    /// Interval subtraction *[a, b] - [c, d] = [a - d, b - c]* clamping the endpoints to the range of `T` instead of overflowing.
+    /// Each bound of the result is inclusive only if the corresponding bounds of both operands are inclusive.
+    /// Return `None` if the clamping makes the result empty, which can happen only when a bound is exclusive.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(i32::MIN + 10).to(Inclusive.at(0));
+    /// let b = Inclusive.at(5).to(Inclusive.at(20));
+    /// assert_eq!(a.saturating_sub(&b), Some(Inclusive.at(i32::MIN).to(Inclusive.at(-5))));
+    ///
+    /// let a = Inclusive.at(0).to(Exclusive.at(10));   // [0, 10)
+    /// let b = Exclusive.at(1).to(Inclusive.at(2));    // (1, 2]
+    /// assert_eq!(a.saturating_sub(&b), Some(Inclusive.at(-2).to(Exclusive.at(9))));
+    /// ```
+    pub fn saturating_sub(&self, other: &Interval<T, R, L>) -> Option<Self>
+    where
+        T: num::traits::SaturatingSub,
+        L: BoundaryOf<Right>,
+        R: BoundaryOf<Left>,
     {
-        Self::new_(self.left.dilate(delta.clone()), self.right.dilate(delta)).unwrap()
+        let left = Bound {
+            limit: self.inf().saturating_sub(other.sup()),
+            bound_type: self.left.bound_type.combine(&other.right.bound_type),
+        };
+        let right = Bound {
+            limit: self.sup().saturating_sub(other.inf()),
+            bound_type: self.right.bound_type.combine(&other.left.bound_type),
+        };
+        Self::try_new(left, right)
     }
 
     /// ```
@@ -307,6 +846,32 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         self.left.includes(&other.left) && self.right.includes(&other.right)
     }
 
+    /// Alias of `includes()`.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(4));
+    /// let b = Inclusive.at(1).to(Exclusive.at(3));
+    /// assert!(a.contains_interval(&b));
+    /// assert!(!b.contains_interval(&a));
+    /// ```
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        self.includes(other)
+    }
+
+    /// Test either a point by `contains()` or an interval by `includes()`.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(4));    // [0, 4)
+    /// let b = Inclusive.at(1).to(Exclusive.at(3));    // [1, 3)
+    /// assert!(a.contains_any(&3));
+    /// assert!(!a.contains_any(&4));
+    /// assert!(a.contains_any(&b));
+    /// assert!(!b.contains_any(&a));
+    /// ```
+    pub fn contains_any(&self, item: &impl Containee<T, L, R>) -> bool {
+        item.is_contained_in(self)
+    }
+
     /// ```
     /// use inter_val::{Interval, Inclusive, Exclusive};
     /// let a = Inclusive.at(0).to(Exclusive.at(3));
@@ -341,6 +906,91 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         )
     }
 
+    /// Crop `self` to lie within `bounds`, i.e., the same as `intersection()`. Return `None` if `self` is entirely outside `bounds`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let bounds = Inclusive.at(0).to(Exclusive.at(10));
+    /// assert_eq!(Inclusive.at(-5).to(Exclusive.at(5)).crop_to(&bounds), Some(Inclusive.at(0).to(Exclusive.at(5))));
+    /// assert_eq!(Inclusive.at(10).to(Exclusive.at(15)).crop_to(&bounds), None);
+    /// ```
+    pub fn crop_to(&self, bounds: &Self) -> Option<Self>
+    where
+        T: Clone,
+    {
+        self.intersection(bounds)
+    }
+
+    /// Measure of the intersection, or zero if `self` and `other` are disjoint.
+    /// Unlike `intersection()`, the intersecting interval is not constructed.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(3));
+    /// let b = Inclusive.at(1).to(Exclusive.at(4));
+    /// let c = Inclusive.at(5).to(Exclusive.at(8));
+    /// assert_eq!(a.overlap_measure(&b), 2);
+    /// assert_eq!(a.overlap_measure(&c), 0);
+    /// ```
+    pub fn overlap_measure(&self, other: &Self) -> T
+    where
+        T: Clone + num::Num,
+    {
+        let left = crate::half::partial_max(&self.left, &other.left);
+        let right = crate::half::partial_min(&self.right, &other.right);
+        if is_valid_interval(left, right) {
+            right.limit.clone() - left.limit.clone()
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Distance between `self` and `other`, i.e., the measure of `gap()`, or zero if they overlap or touch.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(3));
+    /// assert_eq!(a.separation(&Inclusive.at(1).to(Exclusive.at(4))), 0);
+    /// assert_eq!(a.separation(&Inclusive.at(3).to(Exclusive.at(4))), 0);
+    /// assert_eq!(a.separation(&Inclusive.at(5).to(Exclusive.at(8))), 2);
+    /// assert_eq!(a.separation(&Inclusive.at(-4).to(Exclusive.at(-1))), 1);
+    /// ```
+    pub fn separation(&self, other: &Self) -> T
+    where
+        T: Clone + num::Num,
+    {
+        let left = crate::half::partial_max(&self.left, &other.left);
+        let right = crate::half::partial_min(&self.right, &other.right);
+        if right.limit < left.limit {
+            left.limit.clone() - right.limit.clone()
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Lazily yield the intersections of `self` and each item of `others`, skipping disjoint items.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(10));   // [0, 10)
+    /// let others = vec![
+    ///     Inclusive.at(-5).to(Exclusive.at(2)),   // [-5, 2)
+    ///     Inclusive.at(10).to(Exclusive.at(12)),  // [10, 12) is disjoint.
+    ///     Inclusive.at(3).to(Exclusive.at(4)),    // [3, 4)
+    ///     Inclusive.at(8).to(Exclusive.at(20)),   // [8, 20)
+    /// ];
+    /// assert!(a.intersect_stream(others.into_iter()).eq(vec![
+    ///     Inclusive.at(0).to(Exclusive.at(2)),
+    ///     Inclusive.at(3).to(Exclusive.at(4)),
+    ///     Inclusive.at(8).to(Exclusive.at(10)),
+    /// ]));
+    /// ```
+    pub fn intersect_stream<'a>(
+        &'a self,
+        others: impl Iterator<Item = Self> + 'a,
+    ) -> impl Iterator<Item = Self> + 'a
+    where
+        T: Clone,
+    {
+        others.filter_map(move |other| self.intersection(&other))
+    }
+
     /// ```
     /// use inter_val::{Interval, Inclusive, Exclusive};
     /// let a = Inclusive.at(0).to(Exclusive.at(3));
@@ -413,31 +1063,287 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         }
     }
 
-    pub fn lower_bound(&self) -> RightBounded<T, L::Flip>
-    where
-        T: Clone,
-    {
-        self.left.clone().flip()
-    }
-
-    pub fn upper_bound(&self) -> LeftBounded<T, R::Flip>
+    /// Return true if `self` and `other` neither overlap nor have a gap between them.
+    /// ```
+    /// use inter_val::{Interval, BoundType::*};
+    /// let a = Inclusive.at(0).to(Exclusive.at(3));    // [0, 3)
+    /// let b = Inclusive.at(0).to(Inclusive.at(3));    // [0, 3]
+    /// let c = Inclusive.at(3).to(Inclusive.at(5));    // [3, 5]
+    /// let d = Exclusive.at(3).to(Inclusive.at(5));    // (3, 5]
+    /// assert!(a.is_adjacent_to(&c) && c.is_adjacent_to(&a));
+    /// assert!(!b.is_adjacent_to(&c));  // overlap at 3
+    /// assert!(b.is_adjacent_to(&d) && d.is_adjacent_to(&b));
+    /// assert!(!a.is_adjacent_to(&d));  // 3 is left as a gap
+    /// assert!(!a.is_adjacent_to(&a));
+    /// ```
+    pub fn is_adjacent_to(&self, other: &Self) -> bool
     where
         T: Clone,
+        L::Flip: BoundaryOf<Right>,
+        R::Flip: BoundaryOf<Left>,
     {
-        self.right.clone().flip()
+        !self.overlaps(other) && self.gap(other).is_none()
     }
 
+    /// Summarize the relationship between `self` and `other`.
     /// ```
     /// use inter_val::{Interval, Inclusive, Exclusive};
-    /// let a = Inclusive.at(2.1).to(Inclusive.at(5.3));
+    /// let a = Inclusive.at(0).to(Exclusive.at(10));  // [0, 10)
+    /// let b = Inclusive.at(2).to(Exclusive.at(5));   // [2, 5)
+    /// let summary = a.summary(&b);
+    /// assert!(summary.overlaps && summary.includes && !summary.included_by && !summary.touches);
+    /// assert_eq!(summary.overlap_measure, 3);
+    /// assert_eq!(summary.gap_measure, 0);
+    ///
+    /// let c = Inclusive.at(10).to(Exclusive.at(12)); // [10, 12)
+    /// let summary = a.summary(&c);
+    /// assert!(!summary.overlaps && summary.touches);
+    ///
+    /// let d = Inclusive.at(13).to(Exclusive.at(20)); // [13, 20)
+    /// let summary = a.summary(&d);
+    /// assert!(!summary.overlaps && !summary.touches);
+    /// assert_eq!(summary.gap_measure, 3);
+    /// ```
+    pub fn summary(&self, other: &Self) -> IntervalRelationSummary<T>
+    where
+        T: Clone + num::Zero + core::ops::Sub<Output = T>,
+        L::Flip: BoundaryOf<Right>,
+        R::Flip: BoundaryOf<Left>,
+    {
+        let intersection = self.intersection(other);
+        let gap = self.gap(other);
+        IntervalRelationSummary {
+            overlaps: intersection.is_some(),
+            includes: self.includes(other),
+            included_by: other.includes(self),
+            touches: self.is_adjacent_to(other),
+            gap_measure: gap.map_or_else(T::zero, |gap| gap.measure()),
+            overlap_measure: intersection.map_or_else(T::zero, |i| i.measure()),
+        }
+    }
+
+    /// Extend `self` to the span of `self` and `other` in place if they overlap or touch.
+    /// Otherwise `other` is returned as `Err` without modifying `self`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let mut a = Inclusive.at(0).to(Exclusive.at(3));  // [0, 3)
+    /// assert_eq!(a.try_merge_into(Inclusive.at(3).to(Exclusive.at(5))), Ok(()));
+    /// assert_eq!(a, Inclusive.at(0).to(Exclusive.at(5)));
+    ///
+    /// let b = Inclusive.at(6).to(Exclusive.at(8));
+    /// assert_eq!(a.try_merge_into(b), Err(b));
+    /// assert_eq!(a, Inclusive.at(0).to(Exclusive.at(5)));
+    /// ```
+    pub fn try_merge_into(&mut self, other: Self) -> Result<(), Self>
+    where
+        T: Clone,
+        L::Flip: BoundaryOf<Right>,
+        R::Flip: BoundaryOf<Left>,
+    {
+        if self.gap(&other).is_some() {
+            return Err(other);
+        }
+        *self = self.span(&other);
+        Ok(())
+    }
+
+    /// Group indices of `items` into connected clusters, i.e., intervals in a cluster transitively overlap or touch each other,
+    /// and different clusters are separated by gaps.
+    /// Clusters are sorted in ascending order, and indices in each cluster are sorted as well.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let items = [
+    ///     Inclusive.at(10).to(Exclusive.at(12)), // 0: [10, 12)
+    ///     Inclusive.at(0).to(Exclusive.at(3)),   // 1: [0, 3)
+    ///     Inclusive.at(5).to(Exclusive.at(8)),   // 2: [5, 8)
+    ///     Inclusive.at(2).to(Exclusive.at(5)),   // 3: [2, 5) overlaps 1 and touches 2
+    ///     Inclusive.at(11).to(Exclusive.at(15)), // 4: [11, 15)
+    /// ];
+    /// assert_eq!(Interval::connected_components(&items), vec![vec![1, 2, 3], vec![0, 4]]);
+    /// ```
+    pub fn connected_components(items: &[Self]) -> Vec<Vec<usize>>
+    where
+        T: Clone,
+        L::Flip: BoundaryOf<Right>,
+        R::Flip: BoundaryOf<Left>,
+    {
+        let mut indices: Vec<usize> = (0..items.len()).collect();
+        indices.sort_by(|&i, &j| items[i].left_ordering(&items[j]));
+        let mut components: Vec<(Self, Vec<usize>)> = Vec::new();
+        for i in indices {
+            match components.last_mut() {
+                Some((span, component)) if span.gap(&items[i]).is_none() => {
+                    *span = span.span(&items[i]);
+                    component.push(i);
+                }
+                _ => components.push((items[i].clone(), vec![i])),
+            }
+        }
+        components
+            .into_iter()
+            .map(|(_, mut component)| {
+                component.sort_unstable();
+                component
+            })
+            .collect()
+    }
+
+    /// Index to insert `new` into `items` sorted by the left bounds, keeping `items` sorted, found by binary search.
+    /// If some items have the same left bound as `new`, the index after them is returned.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let items = vec![
+    ///     Inclusive.at(0).to(Exclusive.at(3)),
+    ///     Inclusive.at(2).to(Exclusive.at(6)),
+    ///     Inclusive.at(5).to(Exclusive.at(7)),
+    /// ];
+    /// assert_eq!(Interval::insertion_index(&items, &Inclusive.at(-1).to(Exclusive.at(1))), 0);
+    /// assert_eq!(Interval::insertion_index(&items, &Inclusive.at(1).to(Exclusive.at(9))), 1);
+    /// assert_eq!(Interval::insertion_index(&items, &Inclusive.at(2).to(Exclusive.at(3))), 2);
+    /// assert_eq!(Interval::insertion_index(&items, &Inclusive.at(8).to(Exclusive.at(9))), 3);
+    /// ```
+    pub fn insertion_index(items: &[Self], new: &Self) -> usize {
+        items.partition_point(|item| item.left_ordering(new) != core::cmp::Ordering::Greater)
+    }
+
+    /// Find the item containing `t` by binary search in *O(log n)*.
+    /// `items` must be sorted in ascending order and disjoint, e.g., the result of `merge_all()`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let items = vec![
+    ///     Inclusive.at(0).to(Exclusive.at(3)),
+    ///     Inclusive.at(5).to(Exclusive.at(7)),
+    ///     Inclusive.at(7).to(Exclusive.at(9)),
+    /// ];
+    /// assert_eq!(Interval::find_containing(&items, &2), Some(&items[0]));
+    /// assert_eq!(Interval::find_containing(&items, &7), Some(&items[2]));
+    /// assert_eq!(Interval::find_containing(&items, &4), None);
+    /// assert_eq!(Interval::find_containing(&items, &9), None);
+    /// ```
+    pub fn find_containing<'a>(items: &'a [Self], t: &T) -> Option<&'a Self> {
+        let i = items.partition_point(|item| !item.right.contains(t));
+        items.get(i).filter(|item| item.contains(t))
+    }
+
+    /// Merge overlapping or touching intervals and return the result sorted in ascending order.
+    /// `policy` decides whether a single point gap between two exclusive bounds, e.g., *5* between *[0, 5)* and *(5, 10]*, is bridged.
+    /// ```
+    /// use inter_val::{Interval, BoundPolicy, BoundType};
+    /// let a = BoundType::Inclusive.at(0).to(BoundType::Exclusive.at(5));  // [0, 5)
+    /// let b = BoundType::Exclusive.at(5).to(BoundType::Inclusive.at(10)); // (5, 10]
+    /// let c = BoundType::Inclusive.at(10).to(BoundType::Inclusive.at(12)); // [10, 12]
+    ///
+    /// let merged = Interval::coalesce_with_policy([c, b, a], BoundPolicy::Inclusive);
+    /// assert_eq!(merged, vec![BoundType::Inclusive.at(0).to(BoundType::Inclusive.at(12))]);
+    ///
+    /// let merged = Interval::coalesce_with_policy([c, b, a], BoundPolicy::Exclusive);
+    /// assert_eq!(merged, vec![a, BoundType::Exclusive.at(5).to(BoundType::Inclusive.at(12))]);
+    /// ```
+    pub fn coalesce_with_policy(
+        items: impl IntoIterator<Item = Self>,
+        policy: BoundPolicy,
+    ) -> Vec<Self>
+    where
+        T: Clone,
+        L::Flip: BoundaryOf<Right>,
+        R::Flip: BoundaryOf<Left>,
+    {
+        let mut sorted: Vec<Self> = items.into_iter().collect();
+        sorted.sort_by(Self::left_ordering);
+        let mut coalesced: Vec<Self> = Vec::with_capacity(sorted.len());
+        for item in sorted {
+            match coalesced.last_mut() {
+                Some(last) if policy.bridges(last.gap(&item)) => *last = last.span(&item),
+                _ => coalesced.push(item),
+            }
+        }
+        coalesced
+    }
+
+    /// Merge overlapping or adjacent intervals into the minimal set of disjoint intervals sorted in ascending order.
+    /// This is `coalesce_with_policy(items, BoundPolicy::Exclusive)` and runs in *O(n log n)*.
+    /// ```
+    /// use inter_val::{Interval, BoundType::*};
+    /// let items = vec![
+    ///     Inclusive.at(9).to(Exclusive.at(10)),   // [9, 10)
+    ///     Inclusive.at(2).to(Exclusive.at(4)),    // [2, 4)
+    ///     Exclusive.at(6).to(Inclusive.at(8)),    // (6, 8]
+    ///     Inclusive.at(0).to(Exclusive.at(2)),    // [0, 2)
+    ///     Inclusive.at(5).to(Exclusive.at(6)),    // [5, 6)
+    ///     Inclusive.at(3).to(Exclusive.at(5)),    // [3, 5)
+    ///     Exclusive.at(8).to(Inclusive.at(9)),    // (8, 9]
+    /// ];
+    /// assert_eq!(
+    ///     Interval::merge_all(items),
+    ///     vec![
+    ///         Inclusive.at(0).to(Exclusive.at(6)),    // [0, 2) and [2, 4) touch
+    ///         Exclusive.at(6).to(Exclusive.at(10)),   // [5, 6) and (6, 8] don't
+    ///     ]
+    /// );
+    /// ```
+    pub fn merge_all(items: impl IntoIterator<Item = Self>) -> Vec<Self>
+    where
+        T: Clone,
+        L::Flip: BoundaryOf<Right>,
+        R::Flip: BoundaryOf<Left>,
+    {
+        Self::coalesce_with_policy(items, BoundPolicy::Exclusive)
+    }
+
+    /// Check that `parts` cover `self` with no holes, i.e., `self` is included in one of the merged `parts`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(10));
+    /// let parts = [
+    ///     Inclusive.at(4).to(Exclusive.at(12)),
+    ///     Inclusive.at(-1).to(Exclusive.at(4)),
+    /// ];
+    /// assert!(a.is_covered_by(&parts));
+    /// assert!(!a.is_covered_by(&parts[..1]));
+    /// assert!(!a.is_covered_by(&[]));
+    /// ```
+    pub fn is_covered_by(&self, parts: &[Self]) -> bool
+    where
+        T: Clone,
+        L::Flip: BoundaryOf<Right>,
+        R::Flip: BoundaryOf<Left>,
+    {
+        Self::merge_all(parts.iter().cloned())
+            .iter()
+            .any(|merged| merged.includes(self))
+    }
+
+    pub fn lower_bound(&self) -> RightBounded<T, L::Flip>
+    where
+        T: Clone,
+    {
+        self.left.clone().flip()
+    }
+
+    pub fn upper_bound(&self) -> LeftBounded<T, R::Flip>
+    where
+        T: Clone,
+    {
+        self.right.clone().flip()
+    }
+
+    /// Length of the interval, i.e., `sup - inf` of type `T::Output`, e.g., `Duration` for `Instant`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// use std::time::{Duration, Instant};
+    /// let a = Inclusive.at(2.1).to(Inclusive.at(5.3));
     /// assert_eq!(a.measure(), 5.3 - 2.1);
     ///
     /// let a = Exclusive.at(0).to(Exclusive.at(1));    // (0, 1)
     /// assert_eq!(a.measure(), 1);
+    ///
+    /// let now = Instant::now();
+    /// let a = Inclusive.between(now, now + Duration::from_secs(3));
+    /// assert_eq!(a.measure(), Duration::from_secs(3));
     /// ```
-    pub fn measure(&self) -> T
+    pub fn measure(&self) -> T::Output
     where
-        T: Clone + std::ops::Sub<Output = T>,
+        T: Clone + core::ops::Sub,
     {
         self.sup().clone() - self.inf().clone()
     }
@@ -450,7 +1356,7 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
     pub fn step_by(&self, step: T) -> impl Iterator<Item = T> + '_
     where
         T: Clone,
-        for<'a> T: std::ops::AddAssign<&'a T>,
+        for<'a> T: core::ops::AddAssign<&'a T>,
     {
         self.left
             .step_by(step)
@@ -465,13 +1371,33 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
     pub fn step_rev_by(&self, step: T) -> impl Iterator<Item = T> + '_
     where
         T: Clone,
-        for<'a> T: std::ops::SubAssign<&'a T>,
+        for<'a> T: core::ops::SubAssign<&'a T>,
     {
         self.right
             .step_rev_by(step)
             .take_while(|t| self.left.contains(t))
     }
 
+    /// Owning version of `step_by()`, i.e., the returned iterator doesn't borrow the interval.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// fn evens(n: i32) -> impl Iterator<Item = i32> {
+    ///     let a = Inclusive.at(0).to(Exclusive.at(n));    // [0, n)
+    ///     a.into_step_iter(2)
+    /// }
+    /// assert!(evens(9).eq(vec![0, 2, 4, 6, 8]));
+    /// ```
+    pub fn into_step_iter(self, step: T) -> impl Iterator<Item = T>
+    where
+        T: Clone,
+        for<'a> T: core::ops::AddAssign<&'a T>,
+    {
+        let right = self.right;
+        self.left
+            .step_by(step)
+            .take_while(move |t| right.contains(t))
+    }
+
     /// ```
     /// use inter_val::{Interval, Inclusive, Exclusive, Nullable};
     /// let a = Inclusive.at(0).to(Exclusive.at(3));  // [0, 3)
@@ -485,7 +1411,7 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
     /// let sum: Nullable<Interval<_, _, _>> = vec![a, b, c].into_iter().sum();
     /// assert_eq!(sum.unwrap(), span);
     /// ```
-    pub fn span_many<A: std::borrow::Borrow<Self>>(
+    pub fn span_many<A: core::borrow::Borrow<Self>>(
         items: impl IntoIterator<Item = A>,
     ) -> Option<Self>
     where
@@ -496,6 +1422,107 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         Some(items.fold(first, |acc, item| acc.span(item.borrow())))
     }
 
+    /// The smallest interval containing all of `items`. Return `None` if `items` is empty.
+    /// For `Interval<T, BoundType>`, each outer bound is inclusive if any of the contributing endpoints is inclusive.
+    /// ```
+    /// use inter_val::{Interval, BoundType};
+    /// let a = BoundType::Inclusive.at(0).to(BoundType::Exclusive.at(5));   // [0, 5)
+    /// let b = BoundType::Exclusive.at(-2).to(BoundType::Inclusive.at(3));  // (-2, 3]
+    /// let c = BoundType::Inclusive.at(-2).to(BoundType::Inclusive.at(1));  // [-2, 1]
+    /// assert_eq!(
+    ///     Interval::bounding_interval(&[a, b]),
+    ///     Some(BoundType::Exclusive.at(-2).to(BoundType::Exclusive.at(5)))
+    /// );
+    /// assert_eq!(
+    ///     Interval::bounding_interval(&[a, b, c]),
+    ///     Some(BoundType::Inclusive.at(-2).to(BoundType::Exclusive.at(5)))
+    /// );
+    /// assert_eq!(Interval::<i32, BoundType>::bounding_interval(&[]), None);
+    /// ```
+    pub fn bounding_interval(items: &[Self]) -> Option<Self>
+    where
+        T: Clone,
+    {
+        Self::span_many(items)
+    }
+
+    /// The smallest interval containing all of `items`, taking them by value without cloning. Return `None` if `items` is empty.
+    /// Like `bounding_interval()`, each outer bound of `Interval<T, BoundType>` is inclusive if any of the contributing endpoints is inclusive.
+    /// ```
+    /// use inter_val::{Interval, BoundType, Inclusive, Exclusive};
+    /// let items = vec![
+    ///     Inclusive.at(3).to(Exclusive.at(5)),
+    ///     Inclusive.at(0).to(Exclusive.at(4)),
+    ///     Inclusive.at(8).to(Exclusive.at(9)),
+    /// ];
+    /// assert_eq!(Interval::enclosure(items), Some(Inclusive.at(0).to(Exclusive.at(9))));
+    ///
+    /// let a = BoundType::Exclusive.at(0).to(BoundType::Exclusive.at(5));   // (0, 5)
+    /// let b = BoundType::Inclusive.at(0).to(BoundType::Exclusive.at(2));   // [0, 2)
+    /// assert_eq!(Interval::enclosure([a, b]), Some(BoundType::Inclusive.at(0).to(BoundType::Exclusive.at(5))));
+    /// assert_eq!(Interval::<i32>::enclosure([]), None);
+    /// ```
+    pub fn enclosure(items: impl IntoIterator<Item = Self>) -> Option<Self> {
+        items.into_iter().reduce(|a, b| Self {
+            left: crate::half::partial_min(a.left, b.left),
+            right: crate::half::partial_max(a.right, b.right),
+        })
+    }
+
+    /// By-value `span_many()`: endpoints are moved through the fold, so `T` needs no `Clone`.
+    /// Same as `enclosure()`, named to pair with `span_many()`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive};
+    /// let words = vec![
+    ///     Inclusive.at("kiwi".to_string()).to(Inclusive.at("mango".to_string())),
+    ///     Inclusive.at("apple".to_string()).to(Inclusive.at("fig".to_string())),
+    /// ];
+    /// let span = Interval::span_many(&words);
+    /// assert_eq!(Interval::span_many_owned(words), span);
+    /// assert_eq!(span.unwrap().inf(), "apple");
+    /// ```
+    pub fn span_many_owned(items: impl IntoIterator<Item = Self>) -> Option<Self> {
+        Self::enclosure(items)
+    }
+
+    /// Gaps between consecutive intervals of `items`, which must be sorted by their lower bounds.
+    /// Overlapping or adjacent intervals yield no gap, and each gap is measured from everything seen so far,
+    /// so an interval nested in an earlier one does not open a spurious gap.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let busy = vec![
+    ///     Inclusive.at(9).to(Exclusive.at(10)),
+    ///     Inclusive.at(10).to(Exclusive.at(12)),
+    ///     Inclusive.at(13).to(Exclusive.at(17)),
+    ///     Inclusive.at(14).to(Exclusive.at(15)),
+    ///     Inclusive.at(18).to(Exclusive.at(19)),
+    /// ];
+    /// let free: Vec<_> = Interval::gaps(busy).collect();
+    /// assert_eq!(free, vec![
+    ///     Inclusive.at(12).to(Exclusive.at(13)),
+    ///     Inclusive.at(17).to(Exclusive.at(18)),
+    /// ]);
+    /// ```
+    pub fn gaps(
+        items: impl IntoIterator<Item = Self>,
+    ) -> impl Iterator<Item = Interval<T, R::Flip, L::Flip>>
+    where
+        T: Clone,
+        L::Flip: BoundaryOf<Right>,
+        R::Flip: BoundaryOf<Left>,
+    {
+        let mut items = items.into_iter();
+        let first = items.next();
+        items
+            .scan(first, |covered, item| {
+                let covered = covered.as_mut()?;
+                let gap = covered.gap(&item);
+                *covered = covered.span(&item);
+                Some(gap)
+            })
+            .flatten()
+    }
+
     /// ```
     /// use inter_val::{Interval, Nullable};
     /// let hull = Interval::<_>::hull_many(vec![3, 9, 2, 5]).unwrap(); // [2, 9]
@@ -526,6 +1553,24 @@ impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         }
         Self::try_new(left.into(), right.into())
     }
+
+    /// `difference()` for any bound types, computed on `Interval<T, BoundType>` where the bound types of the results are always representable.
+    /// ```
+    /// use inter_val::{Interval, BoundType, Inclusive};
+    /// let a = Inclusive.between(0, 10);  // [0, 10]
+    /// let b = Inclusive.between(3, 5);   // [3, 5]
+    /// let diff = a.difference_general(&b);
+    /// assert_eq!(diff.lower, Some(BoundType::Inclusive.at(0).to(BoundType::Exclusive.at(3))));
+    /// assert_eq!(diff.upper, Some(BoundType::Exclusive.at(5).to(BoundType::Inclusive.at(10))));
+    /// ```
+    pub fn difference_general(&self, other: &Self) -> IntervalDifference<T, BoundType, BoundType>
+    where
+        Self: Clone + Into<Interval<T, BoundType>>,
+        T: Clone,
+    {
+        let general: Interval<T, BoundType> = self.clone().into();
+        general.difference(&other.clone().into())
+    }
 }
 
 impl<T: PartialOrd, L: BoundaryOf<Left, Flip = R>, R: BoundaryOf<Right, Flip = L>>
@@ -540,16 +1585,190 @@ impl<T: PartialOrd, L: BoundaryOf<Left, Flip = R>, R: BoundaryOf<Right, Flip = L
     /// assert!(diff.lower.is_some() && diff.upper.is_none());
     /// assert_eq!(diff.lower.unwrap(), Inclusive.at(0).to(Exclusive.at(1)));
     /// assert_eq!(diff.into_iter().collect::<Vec<_>>().len(), 1);
+    ///
+    /// let c = Inclusive.at(5).to(Exclusive.at(8));
+    /// assert_eq!(a.difference(&c).into_vec(), vec![a]); // Disjoint, so nothing is removed.
     /// ```
     pub fn difference(&self, other: &Self) -> IntervalDifference<T, L, R>
     where
         T: Clone,
     {
         IntervalDifference {
-            lower: Self::new_(self.left.clone(), other.lower_bound()),
-            upper: Self::new_(other.upper_bound(), self.right.clone()),
+            lower: Self::new_(
+                self.left.clone(),
+                self.right.intersection(&other.lower_bound()).clone(),
+            ),
+            upper: Self::new_(
+                self.left.intersection(&other.upper_bound()).clone(),
+                self.right.clone(),
+            ),
+        }
+    }
+
+    /// Remove all `subtrahends` from `self` and return the remaining disjoint pieces in ascending order.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(10));  // [0, 10)
+    /// let busy = [
+    ///     Inclusive.at(6).to(Exclusive.at(8)),  // [6, 8)
+    ///     Inclusive.at(2).to(Exclusive.at(4)),  // [2, 4)
+    ///     Inclusive.at(3).to(Exclusive.at(4)),  // [3, 4)
+    /// ];
+    /// assert_eq!(
+    ///     a.subtract_all(&busy),
+    ///     vec![
+    ///         Inclusive.at(0).to(Exclusive.at(2)),
+    ///         Inclusive.at(4).to(Exclusive.at(6)),
+    ///         Inclusive.at(8).to(Exclusive.at(10)),
+    ///     ]
+    /// );
+    /// assert!(a.subtract_all(&[a]).is_empty());
+    /// ```
+    pub fn subtract_all(&self, subtrahends: &[Self]) -> Vec<Self>
+    where
+        T: Clone,
+    {
+        let coalesced =
+            Self::coalesce_with_policy(subtrahends.iter().cloned(), BoundPolicy::Exclusive);
+        let mut pieces = Vec::new();
+        let mut rest = Some(self.clone());
+        for item in coalesced {
+            let Some(r) = rest.take() else { break };
+            let diff = r.difference(&item);
+            pieces.extend(diff.lower);
+            rest = diff.upper;
+        }
+        pieces.extend(rest);
+        pieces
+    }
+
+    /// The pieces of `self` not covered by any of `parts`, in ascending order.
+    /// This is `subtract_all()` named for coverage diagnostics, so `uncovered_gaps(parts).first()` is the first hole.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let day = Inclusive.at(9).to(Exclusive.at(17));
+    /// let shifts = [
+    ///     Inclusive.at(9).to(Exclusive.at(12)),
+    ///     Inclusive.at(13).to(Exclusive.at(17)),
+    /// ];
+    /// assert!(!day.is_covered_by(&shifts));
+    /// assert_eq!(day.uncovered_gaps(&shifts), vec![Inclusive.at(12).to(Exclusive.at(13))]);
+    /// ```
+    pub fn uncovered_gaps(&self, parts: &[Self]) -> Vec<Self>
+    where
+        T: Clone,
+    {
+        self.subtract_all(parts)
+    }
+}
+
+impl<T: num::Num + Clone, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R> {
+    /// The smallest and the largest integers in the interval. `first > last` if the interval contains no integer, e.g., *(0, 1)*.
+    /// Since the interval is not empty, an exclusive endpoint is never at the limit of `T`, so this never overflows.
+    pub(crate) fn integer_bounds(&self) -> (T, T) {
+        let first = if self.left.bound_type.is_inclusive() {
+            self.left.limit.clone()
+        } else {
+            self.left.limit.clone() + T::one()
+        };
+        let last = if self.right.bound_type.is_inclusive() {
+            self.right.limit.clone()
+        } else {
+            self.right.limit.clone() - T::one()
+        };
+        (first, last)
+    }
+
+    /// Number of the integers in the interval, computed in *O(1)* without iteration.
+    /// Overflows if the count is not representable in `T`, e.g., `[i32::MIN, i32::MAX]`.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// assert_eq!(Exclusive.at(0).to(Inclusive.at(10)).count_integers(), 10);
+    /// assert_eq!(Inclusive.between(0, 10).count_integers(), 11);
+    /// assert_eq!(Exclusive.between(0, 1).count_integers(), 0);
+    /// ```
+    pub fn count_integers(&self) -> T
+    where
+        T: num::Integer,
+    {
+        let (first, last) = self.integer_bounds();
+        if first > last {
+            T::zero()
+        } else {
+            last - first + T::one()
         }
     }
+
+    /// Integer counterpart of `lerp()`, i.e., *inf + (sup - inf) * num / den* computed without floats.
+    /// The result is rounded to the nearest integer, and a tie is rounded toward `sup`.
+    /// The intermediate product is computed in `i128`, so it doesn't overflow for integers up to 64 bits.
+    /// `den` must not be zero, and the result must be representable in `T`.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Inclusive.between(0, 9);
+    /// assert_eq!(a.nth_fraction(1, 3), 3);
+    /// assert_eq!(a.nth_fraction(1, 2), 5);    // 4.5 is rounded toward sup.
+    /// assert_eq!(a.nth_fraction(2, 2), 9);
+    /// assert_eq!(Inclusive.at(-10).to(Exclusive.at(0)).nth_fraction(1, 4), -7); // -7.5
+    /// ```
+    pub fn nth_fraction(&self, num: u32, den: u32) -> T
+    where
+        T: num::Integer + num::NumCast,
+    {
+        assert!(den != 0, "den must not be zero");
+        let width = (self.sup().clone() - self.inf().clone()).to_i128().unwrap();
+        let (num, den) = (i128::from(num), i128::from(den));
+        let offset = (2 * width * num + den) / (2 * den);
+        self.inf().clone() + T::from(offset).unwrap()
+    }
+}
+
+impl<T: Clone> Interval<T, Inclusive> {
+    /// Closed interval consisting of a single point, i.e., *[t, t]*.
+    /// ```
+    /// use inter_val::{Interval, Inclusive};
+    /// let a = Interval::singleton(3);
+    /// assert_eq!(a, Inclusive.between(3, 3));
+    /// assert!(a.contains(&3));
+    /// assert_eq!(a.measure(), 0);
+    /// ```
+    pub fn singleton(t: T) -> Self {
+        Self {
+            left: Inclusive.at(t.clone()).into(),
+            right: Inclusive.at(t).into(),
+        }
+    }
+}
+
+impl<T: num::Bounded + PartialOrd> Interval<T, Inclusive> {
+    /// The whole range of `T`, i.e., *[T::min_value(), T::max_value()]*.
+    /// ```
+    /// use inter_val::{Interval, Inclusive};
+    /// let a = Interval::<i8>::whole();
+    /// assert_eq!(a, Inclusive.between(-128, 127));
+    /// assert!(a.contains(&i8::MIN) && a.contains(&i8::MAX));
+    /// ```
+    pub fn whole() -> Self {
+        Self::new(Inclusive.at(T::min_value()), Inclusive.at(T::max_value()))
+    }
+
+    /// *[t, T::max_value()]*
+    /// ```
+    /// use inter_val::{Interval, Inclusive};
+    /// assert_eq!(Interval::at_least(100i8), Inclusive.between(100, 127));
+    /// ```
+    pub fn at_least(t: T) -> Self {
+        Self::new(Inclusive.at(t), Inclusive.at(T::max_value()))
+    }
+
+    /// *[T::min_value(), t]*
+    /// ```
+    /// use inter_val::{Interval, Inclusive};
+    /// assert_eq!(Interval::at_most(-100i8), Inclusive.between(-128, -100));
+    /// ```
+    pub fn at_most(t: T) -> Self {
+        Self::new(Inclusive.at(T::min_value()), Inclusive.at(t))
+    }
 }
 
 impl<T: PartialOrd + Clone> Interval<T, Inclusive, Exclusive> {
@@ -594,8 +1813,175 @@ impl<T: PartialOrd + Clone> Interval<T, Inclusive, Exclusive> {
         let upper = Self::new_(Inclusive.at(t).into(), self.right.clone());
         (lower.unwrap(), upper.unwrap())
     }
+
+    /// Divide into `n` contiguous sub-intervals of equal width.
+    /// The last one ends exactly at `sup` so that their union equals `self` even if rounding occurs.
+    /// Empty sub-intervals, which arise for integers when `n` exceeds the measure, are skipped.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(10));    // [0, 10)
+    /// assert_eq!(
+    ///     a.partition(5),
+    ///     vec![
+    ///         Inclusive.at(0).to(Exclusive.at(2)),
+    ///         Inclusive.at(2).to(Exclusive.at(4)),
+    ///         Inclusive.at(4).to(Exclusive.at(6)),
+    ///         Inclusive.at(6).to(Exclusive.at(8)),
+    ///         Inclusive.at(8).to(Exclusive.at(10)),
+    ///     ]
+    /// );
+    /// assert!(a.partition(0).is_empty());
+    ///
+    /// let b = Inclusive.at(0.1).to(Exclusive.at(0.7));  // [0.1, 0.7)
+    /// let parts = b.partition(3);
+    /// assert_eq!(parts.len(), 3);
+    /// assert_eq!(parts[0].inf(), b.inf());
+    /// assert_eq!(parts[2].sup(), b.sup());
+    /// for w in parts.windows(2) {
+    ///     assert_eq!(w[0].sup(), w[1].inf());     // contiguous
+    ///     assert!(!w[0].overlaps(&w[1]));         // non-overlapping
+    /// }
+    /// ```
+    pub fn partition(&self, n: usize) -> Vec<Self>
+    where
+        T: num::Num + num::NumCast,
+    {
+        if n == 0 {
+            return Vec::new();
+        }
+        let count = T::from(n).unwrap();
+        let boundary = |i: usize| {
+            if i == n {
+                self.sup().clone()
+            } else {
+                self.inf().clone() + self.measure() * T::from(i).unwrap() / count.clone()
+            }
+        };
+        (0..n)
+            .filter_map(|i| {
+                Self::new_(
+                    Inclusive.at(boundary(i)).into(),
+                    Exclusive.at(boundary(i + 1)).into(),
+                )
+            })
+            .collect()
+    }
+
+    /// Lazily iterate windows *[start, start + width)* where `start` advances from `inf` by `stride`.
+    /// The iteration stops at the first window reaching `sup`.
+    /// That window is clipped to `sup` if `include_partial` is true, otherwise it is dropped.
+    /// `stride` must be positive.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(10));    // [0, 10)
+    ///
+    /// // Overlapping windows
+    /// let windows: Vec<_> = a.windows(4, 3, false).map(|w| (*w.inf(), *w.sup())).collect();
+    /// assert_eq!(windows, vec![(0, 4), (3, 7), (6, 10)]);
+    ///
+    /// // Gapped windows
+    /// let windows: Vec<_> = a.windows(2, 3, false).map(|w| (*w.inf(), *w.sup())).collect();
+    /// assert_eq!(windows, vec![(0, 2), (3, 5), (6, 8)]);
+    /// let windows: Vec<_> = a.windows(2, 3, true).map(|w| (*w.inf(), *w.sup())).collect();
+    /// assert_eq!(windows, vec![(0, 2), (3, 5), (6, 8), (9, 10)]);
+    /// ```
+    pub fn windows(&self, width: T, stride: T, include_partial: bool) -> impl Iterator<Item = Self>
+    where
+        T: core::ops::Add<Output = T>,
+    {
+        let sup = self.sup().clone();
+        let mut start = Some(self.inf().clone());
+        core::iter::from_fn(move || {
+            let lower = start.take().filter(|t| *t < sup)?;
+            let upper = lower.clone() + width.clone();
+            if upper < sup {
+                start = Some(lower.clone() + stride.clone());
+                Self::new_(Inclusive.at(lower).into(), Exclusive.at(upper).into())
+            } else if upper == sup || include_partial {
+                Self::new_(Inclusive.at(lower).into(), Exclusive.at(sup.clone()).into())
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Lazily iterate the contiguous chunks *[t, t + step)* tiling `self`, where the last chunk is clipped to `sup`.
+    /// This is `windows(step, step, true)`. `step` must be positive.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Inclusive.at(0).to(Exclusive.at(10));    // [0, 10)
+    /// let chunks: Vec<_> = a.step_intervals(4).collect();
+    /// assert_eq!(
+    ///     chunks,
+    ///     vec![
+    ///         Inclusive.at(0).to(Exclusive.at(4)),
+    ///         Inclusive.at(4).to(Exclusive.at(8)),
+    ///         Inclusive.at(8).to(Exclusive.at(10)),
+    ///     ]
+    /// );
+    /// ```
+    pub fn step_intervals(&self, step: T) -> impl Iterator<Item = Self>
+    where
+        T: core::ops::Add<Output = T>,
+    {
+        self.windows(step.clone(), step, true)
+    }
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: num::Float> Interval<T, Inclusive, Exclusive> {
+    /// Split into sub-intervals tagged with the value of `pred`.
+    /// This is an approximation: the interval is divided into `resolution` cells of equal width,
+    /// `pred` is sampled at the left end of each cell, and adjacent cells of the same value are merged.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Inclusive.at(0.0).to(Exclusive.at(3.0));  // [0, 3)
+    /// let segments = a.segment_by(|x| (1.0..2.0).contains(&x), 6);
+    /// assert_eq!(
+    ///     segments,
+    ///     vec![
+    ///         (Inclusive.at(0.0).to(Exclusive.at(1.0)), false),
+    ///         (Inclusive.at(1.0).to(Exclusive.at(2.0)), true),
+    ///         (Inclusive.at(2.0).to(Exclusive.at(3.0)), false),
+    ///     ]
+    /// );
+    /// ```
+    pub fn segment_by(&self, pred: impl Fn(T) -> bool, resolution: usize) -> Vec<(Self, bool)> {
+        let mut segments: Vec<(Self, bool)> = Vec::new();
+        let n = T::from(resolution).unwrap();
+        let lerp = |i: usize| self.lerp(T::from(i).unwrap() / n);
+        for i in 0..resolution {
+            let (lower, upper) = (lerp(i), lerp(i + 1));
+            let value = pred(lower);
+            match segments.last_mut() {
+                Some((segment, v)) if *v == value => segment.right.limit = upper,
+                _ => segments.extend(Self::try_between(lower, upper).map(|s| (s, value))),
+            }
+        }
+        segments
+    }
+}
+
+impl Interval<usize, Inclusive, Exclusive> {
+    /// Apply `f` to the elements of `slice` whose indices are contained in the interval.
+    /// The interval is clipped to `0..slice.len()`.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let mut signal = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// Inclusive.at(2).to(Exclusive.at(5)).apply_to_slice(&mut signal, |v| *v = -*v);
+    /// assert_eq!(signal, [1, 2, -3, -4, -5, 6, 7, 8]);
+    ///
+    /// Inclusive.at(6).to(Exclusive.at(100)).apply_to_slice(&mut signal, |v| *v = 0);
+    /// assert_eq!(signal, [1, 2, -3, -4, -5, 6, 0, 0]);
+    /// ```
+    pub fn apply_to_slice<V>(&self, slice: &mut [V], f: impl FnMut(&mut V)) {
+        let end = (*self.sup()).min(slice.len());
+        let begin = (*self.inf()).min(end);
+        slice[begin..end].iter_mut().for_each(f);
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
 impl<T: num::Float, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R> {
     /// ```
     /// use inter_val::{Interval, Inclusive};
@@ -606,6 +1992,23 @@ impl<T: num::Float, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         (self.left.limit + self.right.limit) / (T::one() + T::one())
     }
 
+    /// Move the interval so that its center lands on `c`, keeping the `measure()` and the bound types.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0.0).to(Inclusive.at(4.0));    // [0, 4]
+    /// assert_eq!(a.centered_at(10.0), Inclusive.at(8.0).to(Inclusive.at(12.0)));
+    ///
+    /// let b = Exclusive.at(-1.0).to(Inclusive.at(2.0));   // (-1, 2]
+    /// assert_eq!(b.centered_at(0.0), Exclusive.at(-1.5).to(Inclusive.at(1.5)));
+    /// ```
+    pub fn centered_at(&self, c: T) -> Self {
+        let delta = c - self.center();
+        Self {
+            left: self.left.0.map(|t| t + delta).into(),
+            right: self.right.0.map(|t| t + delta).into(),
+        }
+    }
+
     /// IoU - Intersection over Union.
     /// ```
     /// use inter_val::{Interval, Inclusive};
@@ -625,6 +2028,86 @@ impl<T: num::Float, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
             .unwrap_or(T::zero())
     }
 
+    /// Fraction of `self` covered by `other`, i.e., `overlap_measure(other) / measure()`, in *[0, 1]*.
+    /// Unlike `iou()`, this is asymmetric. A zero-width `self` like *[a, a]* gives one if `other` contains *a* and zero otherwise.
+    /// ```
+    /// use inter_val::{Interval, Inclusive};
+    /// let a = Inclusive.at(0.0).to(Inclusive.at(1.0));
+    /// let b = Inclusive.at(0.0).to(Inclusive.at(4.0));
+    /// assert_eq!(a.coverage_ratio(&b), 1.0);
+    /// assert_eq!(b.coverage_ratio(&a), 0.25);
+    /// assert_eq!(Interval::between(2.0, 2.0).coverage_ratio(&b), 1.0);
+    /// ```
+    pub fn coverage_ratio(&self, other: &Self) -> T {
+        let measure = self.measure();
+        if measure > T::zero() {
+            self.overlap_measure(other) / measure
+        } else if other.includes(self) {
+            T::one()
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Sum of `value * overlap` over the segments, where `overlap` is the measure of the intersection of `query` and each segment.
+    /// Useful for time-weighted aggregation over piecewise-constant data.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let rates = [
+    ///     (Inclusive.at(0.0).to(Exclusive.at(10.0)), 2.0),   // [0, 10) at rate 2
+    ///     (Inclusive.at(10.0).to(Exclusive.at(20.0)), 5.0),  // [10, 20) at rate 5
+    ///     (Inclusive.at(30.0).to(Exclusive.at(40.0)), 9.0),  // [30, 40) doesn't overlap
+    /// ];
+    /// let query = Inclusive.at(5.0).to(Exclusive.at(15.0)); // [5, 15)
+    /// assert_eq!(Interval::weighted_sum(&query, &rates), 5.0 * 2.0 + 5.0 * 5.0);
+    /// ```
+    pub fn weighted_sum<V>(query: &Self, segments: &[(Self, V)]) -> V
+    where
+        V: Clone + num::Zero + core::ops::Mul<T, Output = V>,
+    {
+        segments
+            .iter()
+            .filter_map(|(segment, value)| {
+                let overlap = query.intersection(segment)?.measure();
+                Some(value.clone() * overlap)
+            })
+            .fold(V::zero(), |acc, v| acc + v)
+    }
+
+    /// Advanced helper: `measure()` shrunk by `eps` for each exclusive endpoint.
+    /// This is the practically usable width when an exclusive bound is treated as excluding an `eps`-neighborhood,
+    /// e.g., `eps` is the resolution of sampling.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let eps = 1e-3;
+    /// assert_eq!(Exclusive.at(0.0).to(Exclusive.at(1.0)).effective_measure(eps), 1.0 - 2.0 * eps);
+    /// assert_eq!(Inclusive.at(0.0).to(Exclusive.at(1.0)).effective_measure(eps), 1.0 - eps);
+    /// assert_eq!(Inclusive.at(0.0).to(Inclusive.at(1.0)).effective_measure(eps), 1.0);
+    /// ```
+    pub fn effective_measure(&self, eps: T) -> T {
+        let mut measure = self.measure();
+        if self.left.bound_type.is_exclusive() {
+            measure = measure - eps;
+        }
+        if self.right.bound_type.is_exclusive() {
+            measure = measure - eps;
+        }
+        measure
+    }
+
+    /// `contains()` with tolerance, i.e., *inf - epsilon ≤ t ≤ sup + epsilon* regardless of the bound types.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0.0).to(Exclusive.at(0.3));  // [0, 0.3)
+    /// assert!(!a.contains(&(0.1 + 0.2)));
+    /// assert!(a.contains_within(0.1 + 0.2, 1e-9));
+    /// assert!(a.contains_within(-1e-10, 1e-9));
+    /// assert!(!a.contains_within(0.31, 1e-9));
+    /// ```
+    pub fn contains_within(&self, t: T, epsilon: T) -> bool {
+        *self.inf() - epsilon <= t && t <= *self.sup() + epsilon
+    }
+
     /// Linear interpolation.
     /// ```
     /// use inter_val::{Interval, Inclusive, Exclusive};
@@ -633,11 +2116,208 @@ impl<T: num::Float, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
     /// assert_eq!(a.lerp(0.5), 3.0);
     /// assert_eq!(a.lerp(1.0), 4.0);
     /// assert_eq!(a.lerp(1.1), 4.2);
+    ///
+    /// let b = Inclusive.at(1.23).to(Exclusive.at(4.56));  // [1.23, 4.56)
+    /// assert_eq!(b.lerp(0.0), *b.inf());
+    /// assert_eq!(b.lerp(1.0), *b.sup());
+    /// assert_eq!(b.lerp(0.2), 0.8 * *b.inf() + 0.2 * *b.sup());
     /// ```
     pub fn lerp(&self, ratio: T) -> T {
         (T::one() - ratio) * *self.inf() + ratio * *self.sup()
     }
 
+    /// Inverse of `lerp()`, i.e., *(t - inf) / (sup - inf)*.
+    /// Return zero for a degenerate interval *[a, a]* instead of NaN.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(2.0).to(Inclusive.at(4.0));    // [2, 4]
+    /// assert_eq!(a.inverse_lerp(3.0), 0.5);
+    /// assert_eq!(a.inverse_lerp(2.0), 0.0);
+    /// assert_eq!(a.inverse_lerp(5.0), 1.5);
+    /// assert_eq!(a.inverse_lerp(a.lerp(0.25)), 0.25);
+    ///
+    /// let b = Inclusive.at(2.0).to(Inclusive.at(2.0));    // [2, 2]
+    /// assert_eq!(b.inverse_lerp(2.0), 0.0);
+    /// ```
+    pub fn inverse_lerp(&self, t: T) -> T {
+        let measure = self.measure();
+        if measure.is_zero() {
+            T::zero()
+        } else {
+            (t - *self.inf()) / measure
+        }
+    }
+
+    /// Map `t` from `self` onto `to` keeping the relative position, i.e., `to.lerp(self.inverse_lerp(t))`.
+    /// Values outside `self` are extrapolated linearly.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0.0).to(Inclusive.at(1.0));    // [0, 1]
+    /// let b = Inclusive.at(10.0).to(Exclusive.at(20.0));  // [10, 20)
+    /// assert_eq!(a.remap(0.25, &b), 12.5);
+    /// assert_eq!(a.remap(1.5, &b), 25.0);
+    /// assert_eq!(b.remap(12.5, &a), 0.25);
+    /// ```
+    pub fn remap<L2: BoundaryOf<Left>, R2: BoundaryOf<Right>>(
+        &self,
+        t: T,
+        to: &Interval<T, L2, R2>,
+    ) -> T {
+        to.lerp(self.inverse_lerp(t))
+    }
+
+    /// Return a closure mapping a value from this interval into *[0, 1]*, i.e., the same as `inverse_lerp()`.
+    /// The closure returns zero for a degenerate interval *[a, a]* like `inverse_lerp()`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive};
+    /// let a = Inclusive.at(2.0).to(Inclusive.at(6.0));    // [2, 6]
+    /// let normalize = a.normalizer();
+    /// assert_eq!(normalize(2.0), 0.0);
+    /// assert_eq!(normalize(3.0), 0.25);
+    /// assert_eq!(normalize(6.0), 1.0);
+    /// ```
+    pub fn normalizer(&self) -> impl Fn(T) -> T {
+        let this = *self;
+        move |t| this.inverse_lerp(t)
+    }
+
+    /// Return a closure mapping a value from *[0, 1]* into this interval, i.e., the same as `lerp()`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive};
+    /// let a = Inclusive.at(2.0).to(Inclusive.at(6.0));    // [2, 6]
+    /// let denormalize = a.denormalizer();
+    /// assert_eq!(denormalize(0.0), 2.0);
+    /// assert_eq!(denormalize(0.25), 3.0);
+    /// assert_eq!(denormalize(1.0), 6.0);
+    /// ```
+    pub fn denormalizer(&self) -> impl Fn(T) -> T {
+        let (inf, sup) = (*self.inf(), *self.sup());
+        move |ratio| (T::one() - ratio) * inf + ratio * sup
+    }
+
+    /// Scale the distances from `anchor` to the endpoints by `factor`, i.e., the anchor stays put.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0.0).to(Exclusive.at(10.0));  // [0, 10)
+    /// assert_eq!(a.scale_about(2.0, 2.0), Inclusive.at(-2.0).to(Exclusive.at(18.0)));
+    /// assert_eq!(a.scale_about(10.0, 0.5), Inclusive.at(5.0).to(Exclusive.at(10.0)));
+    /// ```
+    /// ```should_panic
+    /// use inter_val::{Interval, Inclusive};
+    /// let a = Inclusive.at(0.0).to(Inclusive.at(10.0));
+    /// a.scale_about(5.0, -1.0);  // panic! `factor` must not be negative.
+    /// ```
+    pub fn scale_about(self, anchor: T, factor: T) -> Self {
+        let f = |t: T| anchor + (t - anchor) * factor;
+        Self::new_(self.left.0.map(f).into(), self.right.0.map(f).into()).unwrap()
+    }
+
+    /// Scale the width by `factor` about the center, i.e., `scale_about(self.center(), factor)`.
+    /// Unlike `dilate()` adding an absolute delta, the change is proportional to the width.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0.0).to(Exclusive.at(10.0));  // [0, 10)
+    /// assert_eq!(a.scale(0.5), Inclusive.at(2.5).to(Exclusive.at(7.5)));
+    /// assert_eq!(a.scale(2.0), Inclusive.at(-5.0).to(Exclusive.at(15.0)));
+    /// assert_eq!(a.scale(2.0).measure(), 2.0 * a.measure());
+    /// ```
+    /// ```should_panic
+    /// use inter_val::{Interval, Inclusive};
+    /// let a = Inclusive.at(0.0).to(Inclusive.at(10.0));
+    /// a.scale(0.0);  // panic! `factor` must be positive.
+    /// ```
+    pub fn scale(&self, factor: T) -> Self {
+        assert!(factor > T::zero(), "factor must be positive");
+        self.scale_about(self.center(), factor)
+    }
+
+    /// Round both endpoints to the nearest grid points `origin + k * step` (`k` is an integer), keeping the bound types.
+    /// Each endpoint may move in either direction. Return `None` if the result is empty.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(2.3).to(Inclusive.at(7.8));
+    /// assert_eq!(a.quantize_to_step(1.0, 0.0), Some(Inclusive.at(2.0).to(Inclusive.at(8.0))));
+    ///
+    /// let a = Inclusive.at(2.3).to(Exclusive.at(7.8));
+    /// assert_eq!(a.quantize_to_step(2.0, 1.0), Some(Inclusive.at(3.0).to(Exclusive.at(7.0))));
+    ///
+    /// let a = Inclusive.at(2.3).to(Exclusive.at(2.4));
+    /// assert_eq!(a.quantize_to_step(1.0, 0.0), None);   // [2, 2) is empty.
+    /// ```
+    pub fn quantize_to_step(self, step: T, origin: T) -> Option<Self> {
+        let f = |t: T| origin + ((t - origin) / step).round() * step;
+        Self::new_(self.left.0.map(f).into(), self.right.0.map(f).into())
+    }
+
+    /// Round outward to integers, i.e., floor `inf` and ceil `sup`, and cast into `U` keeping the bound types.
+    /// Return `None` if an endpoint is not representable in `U`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(-1.5).to(Inclusive.at(2.5));  // [-1.5, 2.5]
+    /// assert_eq!(a.round_out::<i32>(), Some(Inclusive.between(-2, 3)));
+    ///
+    /// let b = Inclusive.at(-1.5).to(Exclusive.at(2.0));  // [-1.5, 2.0)
+    /// assert_eq!(b.round_out::<i32>(), Some(Inclusive.at(-2).to(Exclusive.at(2))));
+    /// assert_eq!(b.round_out::<u32>(), None);
+    /// ```
+    pub fn round_out<U: num::NumCast>(self) -> Option<Interval<U, L, R>> {
+        Interval {
+            left: self.left.0.map(T::floor).into(),
+            right: self.right.0.map(T::ceil).into(),
+        }
+        .try_cast()
+    }
+
+    /// Round inward to integers, i.e., the closed interval of the integers contained, cast into `U`.
+    /// An exclusive endpoint becomes inclusive, e.g., *[0.5, 2.5)* and *(0, 3)* are both rounded to *[1, 2]*.
+    /// Return `None` if no integer is contained or an endpoint is not representable in `U`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(-1.5).to(Inclusive.at(2.5));  // [-1.5, 2.5]
+    /// assert_eq!(a.round_in::<i32>(), Some(Inclusive.between(-1, 2)));
+    ///
+    /// let b = Inclusive.at(0.5).to(Exclusive.at(2.5));   // [0.5, 2.5)
+    /// assert_eq!(b.round_in::<i32>(), Some(Inclusive.between(1, 2)));
+    /// assert_eq!(Inclusive.between(1.2, 1.8).round_in::<i32>(), None);
+    /// ```
+    pub fn round_in<U: num::NumCast>(self) -> Option<Interval<U, Inclusive>> {
+        let (inf, sup) = (self.inf().ceil(), self.sup().floor());
+        let first = if self.left.contains(&inf) {
+            inf
+        } else {
+            inf + T::one()
+        };
+        let last = if self.right.contains(&sup) {
+            sup
+        } else {
+            sup - T::one()
+        };
+        Interval::try_new(Inclusive.at(first), Inclusive.at(last))?.try_cast()
+    }
+
+    /// Step to yield approximately `n` samples by `step_by()`.
+    /// * `include_sup == false`: `measure() / n`, i.e., `n` samples starting at `inf` without reaching `sup`.
+    /// * `include_sup == true`: `measure() / (n - 1)`, i.e., `n` samples including both `inf` and `sup`.
+    ///
+    /// With nothing to divide by, i.e., `n == 0`, or `n == 1` and `include_sup == true`, return `measure()` itself.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0.0).to(Inclusive.at(10.0));   // [0, 10]
+    /// assert_eq!(a.step_for_count(5, false), 2.0);  // 0, 2, 4, 6, 8
+    /// assert_eq!(a.step_for_count(5, true), 2.5);   // 0, 2.5, 5, 7.5, 10
+    ///
+    /// let b = Inclusive.at(0.0).to(Exclusive.at(10.0));   // [0, 10)
+    /// assert_eq!(b.step_by(b.step_for_count(5, false)).count(), 5);
+    /// assert_eq!(a.step_by(a.step_for_count(5, true)).count(), 5);
+    /// ```
+    pub fn step_for_count(&self, n: usize, include_sup: bool) -> T {
+        let divisions = if include_sup { n.saturating_sub(1) } else { n };
+        if divisions == 0 {
+            return self.measure();
+        }
+        self.measure() / T::from(divisions).unwrap()
+    }
+
     /// ```
     /// use inter_val::{Interval, Inclusive, Exclusive};
     /// let a = Inclusive.at(2.0).to(Inclusive.at(4.0));    // [2, 4]
@@ -659,13 +2339,99 @@ impl<T: num::Float, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Interval<T, L, R>
         } else {
             n - 1
         };
-        std::iter::from_fn(move || {
+        core::iter::from_fn(move || {
             let ret = (i <= last).then_some(t);
             t = if i == n { *self.sup() } else { t + step };
             i += 1;
             ret
         })
     }
+
+    /// Quantize to an integer interval *[m, m + n)* where `m` is the rounded `inf` and `n` is the rounded `measure()`.
+    /// Unlike rounding both endpoints independently, the rounded length is preserved exactly.
+    /// Return `None` if the rounded length is zero.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(1.4).to(Inclusive.at(5.4));   // [1.4, 5.4], length 4
+    /// assert_eq!(a.quantize_preserving_length(), Some(Inclusive.at(1).to(Exclusive.at(5))));
+    ///
+    /// // Independent rounding would give [2, 5) of length 3, but the rounded length is 4.
+    /// let a = Inclusive.at(1.5).to(Inclusive.at(5.4));   // [1.5, 5.4], length 3.9
+    /// assert_eq!(a.quantize_preserving_length(), Some(Inclusive.at(2).to(Exclusive.at(6))));
+    ///
+    /// let a = Inclusive.at(1.4).to(Inclusive.at(1.6));
+    /// assert_eq!(a.quantize_preserving_length(), None);
+    /// ```
+    pub fn quantize_preserving_length(&self) -> Option<Interval<i64, Inclusive, Exclusive>> {
+        let inf = self.inf().round().to_i64()?;
+        let len = self.measure().round().to_i64()?;
+        Interval::try_new(Inclusive.at(inf), Exclusive.at(inf + len))
+    }
+
+    /// Range of `k` of the contained lattice points, or `None` if `spacing` is not positive and finite or `k` doesn't fit in `i64`.
+    fn lattice_range(&self, origin: T, spacing: T) -> Option<(i64, i64)> {
+        if !(spacing > T::zero() && spacing.is_finite()) {
+            return None;
+        }
+        let point = |k: i64| origin + T::from(k).unwrap() * spacing;
+        let mut first = ((*self.inf() - origin) / spacing).ceil().to_i64()?;
+        while let Some(k) = first
+            .checked_sub(1)
+            .filter(|&k| self.left.contains(&point(k)))
+        {
+            first = k;
+        }
+        while !self.left.contains(&point(first)) {
+            first = first.checked_add(1)?;
+        }
+        let mut last = ((*self.sup() - origin) / spacing).floor().to_i64()?;
+        while let Some(k) = last
+            .checked_add(1)
+            .filter(|&k| self.right.contains(&point(k)))
+        {
+            last = k;
+        }
+        while !self.right.contains(&point(last)) {
+            last = last.checked_sub(1)?;
+        }
+        Some((first, last))
+    }
+
+    /// Number of lattice points `origin + k * spacing` (`k` is an integer) contained in the interval.
+    /// Zero if `spacing` is not positive and finite, or if `k` of the points doesn't fit in `i64`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0.0).to(Exclusive.at(10.0));  // [0, 10)
+    /// assert_eq!(a.lattice_count(1.0, 3.0), 3);  // 1, 4, 7
+    ///
+    /// let a = Inclusive.at(0.0).to(Inclusive.at(10.0));  // [0, 10]
+    /// assert_eq!(a.lattice_count(1.0, 3.0), 4);  // 1, 4, 7, 10
+    ///
+    /// let a = Exclusive.at(1.0).to(Exclusive.at(4.0));   // (1, 4)
+    /// assert_eq!(a.lattice_count(1.0, 3.0), 0);
+    /// ```
+    pub fn lattice_count(&self, origin: T, spacing: T) -> usize {
+        self.lattice_range(origin, spacing)
+            .map_or(0, |(first, last)| {
+                (last as i128 - first as i128 + 1).max(0) as usize
+            })
+    }
+
+    /// Lattice points `origin + k * spacing` (`k` is an integer) contained in the interval.
+    /// Each point is computed from `k` directly, so errors don't accumulate.
+    /// Empty if `spacing` is not positive and finite, or if `k` of the points doesn't fit in `i64`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(0.0).to(Exclusive.at(10.0));  // [0, 10)
+    /// assert!(a.lattice_points(1.0, 3.0).eq(vec![1.0, 4.0, 7.0]));
+    ///
+    /// let a = Inclusive.at(-1.0).to(Inclusive.at(1.0));  // [-1, 1]
+    /// assert!(a.lattice_points(0.0, 0.5).eq(vec![-1.0, -0.5, 0.0, 0.5, 1.0]));
+    /// ```
+    pub fn lattice_points(&self, origin: T, spacing: T) -> impl Iterator<Item = T> {
+        let (first, last) = self.lattice_range(origin, spacing).unwrap_or((0, -1));
+        (first..=last).map(move |k| origin + T::from(k).unwrap() * spacing)
+    }
 }
 
 impl<T, L, R> Interval<T, L, R> {
@@ -682,6 +2448,81 @@ impl<T, L, R> Interval<T, L, R> {
             right: self.right.cast(),
         }
     }
+
+    /// Wrap in `Nullable` to chain null-aware set operations.
+    /// ```
+    /// use inter_val::{Interval, Inclusive};
+    /// let a = Inclusive.between(0, 5).as_nullable();
+    /// assert_eq!(a.intersection(&Inclusive.between(3, 8)).unwrap(), Inclusive.between(3, 5));
+    /// ```
+    pub fn as_nullable(self) -> Nullable<Self> {
+        self.into()
+    }
+
+    /// Apply `f` to both endpoints preserving the bound types, e.g., for unit conversions.
+    /// The result is not validated, so the caller must guarantee that `f` keeps the interval non-empty, e.g., `f` is monotonically increasing.
+    /// Use `try_map()` to validate the result.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let seconds = Inclusive.at(1800).to(Exclusive.at(5400));   // [1800, 5400)
+    /// let hours = seconds.map(|s| s as f64 / 3600.0);
+    /// assert_eq!(hours, Inclusive.at(0.5).to(Exclusive.at(1.5)));
+    /// ```
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Interval<U, L, R> {
+        Interval {
+            left: self.left.0.map(&f).into(),
+            right: self.right.0.map(&f).into(),
+        }
+    }
+
+    /// Apply `f` to both endpoints preserving the bound types, and return `None` if the result is empty.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(1).to(Exclusive.at(3));    // [1, 3)
+    /// assert_eq!(a.try_map(|x| x * 10), Some(Inclusive.at(10).to(Exclusive.at(30))));
+    /// assert_eq!(a.try_map(|x| -x), None);    // [-1, -3) is empty.
+    /// assert_eq!(a.try_map(|_| 0), None);     // [0, 0) is empty.
+    /// ```
+    pub fn try_map<U: PartialOrd>(self, f: impl Fn(T) -> U) -> Option<Interval<U, L, R>>
+    where
+        L: BoundaryOf<Left>,
+        R: BoundaryOf<Right>,
+    {
+        let mapped = self.map(f);
+        Interval::new_(mapped.left, mapped.right)
+    }
+
+    /// Apply a monotonically increasing function `f` to both endpoints.
+    /// The result is not validated, so `f` must be monotonically increasing on the interval.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(1).to(Inclusive.at(3));    // [1, 3]
+    /// assert_eq!(a.map_monotonic(|x| x * 2), Inclusive.at(2).to(Inclusive.at(6)));
+    ///
+    /// let a = Inclusive.at(1.0).to(Exclusive.at(std::f64::consts::E)); // [1, e)
+    /// assert_eq!(a.map_monotonic(f64::ln), Inclusive.at(0.0).to(Exclusive.at(1.0)));
+    /// ```
+    pub fn map_monotonic<U>(self, f: impl Fn(T) -> U) -> Interval<U, L, R> {
+        self.map(f)
+    }
+
+    /// Apply a monotonically decreasing function `f` to both endpoints.
+    /// The endpoints are swapped, so the left bound type of the result is the right bound type of `self` and vice versa.
+    /// The result is not validated, so `f` must be monotonically decreasing on the interval.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(1).to(Inclusive.at(3));    // [1, 3]
+    /// assert_eq!(a.map_monotonic_decreasing(|x| -x), Inclusive.at(-3).to(Inclusive.at(-1)));
+    ///
+    /// let a = Inclusive.at(1).to(Exclusive.at(3));    // [1, 3)
+    /// assert_eq!(a.map_monotonic_decreasing(|x| -x), Exclusive.at(-3).to(Inclusive.at(-1)));
+    /// ```
+    pub fn map_monotonic_decreasing<U>(self, f: impl Fn(T) -> U) -> Interval<U, R, L> {
+        Interval {
+            left: self.right.0.map(&f).into(),
+            right: self.left.0.map(&f).into(),
+        }
+    }
 }
 
 impl<T: num::NumCast, L, R> Interval<T, L, R> {
@@ -699,6 +2540,99 @@ impl<T: num::NumCast, L, R> Interval<T, L, R> {
             right: self.right.try_cast()?,
         })
     }
+
+    /// Cast by `num::NumCast`, clamping the endpoints out of the range of `U` to `U::min_value()` or `U::max_value()`.
+    /// The bound types are kept as is. Return `None` if the clamped result is empty, e.g., *(a, b)* where both `a` and `b` exceed `U::max_value()`.
+    ///
+    /// Casting may lose precision, e.g., float endpoints are truncated toward zero and large `i64` endpoints are rounded when cast into `f32`.
+    /// A NaN endpoint is clamped to `U::max_value()`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(-10i64).to(Exclusive.at(1i64 << 40));
+    /// assert_eq!(a.saturating_cast::<i32>(), Some(Inclusive.at(-10).to(Exclusive.at(i32::MAX))));
+    /// assert_eq!(a.saturating_cast::<u8>(), Some(Inclusive.at(0).to(Exclusive.at(255))));
+    /// assert_eq!(Exclusive.between(300, 400).saturating_cast::<u8>(), None);
+    /// ```
+    pub fn saturating_cast<U>(self) -> Option<Interval<U, L, R>>
+    where
+        T: PartialOrd,
+        U: num::NumCast + num::Bounded + PartialOrd,
+        L: BoundaryOf<Left>,
+        R: BoundaryOf<Right>,
+    {
+        fn saturate<T: num::NumCast + PartialOrd, U: num::NumCast + num::Bounded>(t: T) -> U {
+            match T::from(U::min_value()) {
+                Some(min) if t < min => U::min_value(),
+                _ => U::from(t).unwrap_or_else(U::max_value),
+            }
+        }
+        Interval::new_(
+            self.left.0.map(saturate).into(),
+            self.right.0.map(saturate).into(),
+        )
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+impl<T: ordered_float::FloatCore, L, R> Interval<T, L, R> {
+    /// Convert into an interval of `NotNan<T>`. Return `Err` if any endpoint is `NaN`.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// use ordered_float::NotNan;
+    /// let a = Inclusive.at(1.0).to(Exclusive.at(2.0)).into_not_nan().unwrap();
+    /// assert_eq!(a.inf(), &NotNan::new(1.0).unwrap());
+    /// assert_eq!(a.sup(), &NotNan::new(2.0).unwrap());
+    ///
+    /// let a: Interval<f64> = Interval::between(1.0, 2.0).map_monotonic(|_| f64::NAN);
+    /// assert!(a.into_not_nan().is_err());
+    /// ```
+    pub fn into_not_nan(
+        self,
+    ) -> Result<Interval<ordered_float::NotNan<T>, L, R>, ordered_float::FloatIsNan> {
+        Ok(Interval {
+            left: Bound {
+                limit: ordered_float::NotNan::new(self.left.0.limit)?,
+                bound_type: self.left.0.bound_type,
+            }
+            .into(),
+            right: Bound {
+                limit: ordered_float::NotNan::new(self.right.0.limit)?,
+                bound_type: self.right.0.bound_type,
+            }
+            .into(),
+        })
+    }
+}
+
+/// ```
+/// use inter_val::{BoundType, Inclusive, Exclusive};
+/// assert_eq!(format!("{}", Inclusive.at(0).to(Exclusive.at(3))), "[0, 3)");
+/// assert_eq!(format!("{}", Exclusive.at(-1.5).to(Inclusive.at(2.5))), "(-1.5, 2.5]");
+/// assert_eq!(format!("{}", Inclusive.between(1, 2)), "[1, 2]");
+/// assert_eq!(format!("{}", BoundType::Exclusive.at(1).to(BoundType::Exclusive.at(2))), "(1, 2)");
+///
+/// // Formatting options apply to each limit.
+/// assert_eq!(format!("{:.2}", Inclusive.at(0.0).to(Exclusive.at(1.0 / 3.0))), "[0.00, 0.33)");
+/// assert_eq!(format!("{:+}", Inclusive.between(-1, 2)), "[-1, +2]");
+/// ```
+impl<T: core::fmt::Display, L: Boundary, R: Boundary> core::fmt::Display for Interval<T, L, R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let l = if self.left.bound_type.is_inclusive() {
+            '['
+        } else {
+            '('
+        };
+        let r = if self.right.bound_type.is_inclusive() {
+            ']'
+        } else {
+            ')'
+        };
+        write!(f, "{l}")?;
+        self.left.limit.fmt(f)?;
+        f.write_str(", ")?;
+        self.right.limit.fmt(f)?;
+        write!(f, "{r}")
+    }
 }
 
 impl<T, L: IntoGeneral, R: IntoGeneral> IntoGeneral for Interval<T, L, R> {
@@ -727,20 +2661,42 @@ impl<T, L: IntoGeneral, R: IntoGeneral> IntoGeneral for Interval<T, L, R> {
 /// assert_eq!(items.len(), 10);
 /// assert_eq!(items[0], 1);
 /// assert_eq!(items.last().unwrap(), &10);
+///
+/// // No integer in (0, 1)
+/// assert_eq!(Exclusive.between(0, 1).into_iter().count(), 0);
+///
+/// // Reverse iteration and exact length
+/// let items = Inclusive.at(0u8).to(Exclusive.at(5)).into_iter();
+/// assert_eq!(items.len(), 5);
+/// assert!(items.rev().eq([4, 3, 2, 1, 0]));
 /// ```
 impl<T, L, R> IntoIterator for Interval<T, L, R>
 where
-    std::ops::RangeInclusive<T>: Iterator<Item = T>,
-    T: num::Integer + Clone,
+    T: num::Integer + num::CheckedSub + num::ToPrimitive + Clone,
     L: BoundaryOf<Left>,
     R: BoundaryOf<Right>,
-    for<'a> T: std::ops::AddAssign<&'a T> + std::ops::SubAssign<&'a T>,
 {
     type Item = T;
-    type IntoIter = std::ops::RangeInclusive<T>;
+    type IntoIter = IntegerIter<T>;
     fn into_iter(self) -> Self::IntoIter {
-        let first = self.left.step_by(T::one()).next().unwrap();
-        let last = self.right.step_rev_by(T::one()).next().unwrap();
-        first..=last
+        let (first, last) = self.integer_bounds();
+        IntegerIter {
+            exhausted: first > last,
+            front: first,
+            back: last,
+        }
+    }
+}
+
+impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Containee<T, L, R> for T {
+    fn is_contained_in(&self, interval: &Interval<T, L, R>) -> bool {
+        interval.contains(self)
+    }
+}
+impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> Containee<T, L, R>
+    for Interval<T, L, R>
+{
+    fn is_contained_in(&self, interval: &Interval<T, L, R>) -> bool {
+        interval.includes(self)
     }
 }
@@ -0,0 +1,141 @@
+use crate::bound_type::{Left, Right};
+use crate::traits::BoundaryOf;
+use crate::{Bound, Inclusive, Interval};
+use alloc::vec::Vec;
+
+/// Batch of intervals stored as a structure of arrays, i.e., the `inf`s and `sup`s in separate `Vec`s.
+/// Unlike [`IntervalSet`](crate::IntervalSet), the intervals are neither merged nor sorted, and are kept in insertion order.
+/// Queries scan the contiguous arrays and return the indices of the matching intervals.
+/// ```
+/// use inter_val::{Inclusive, Exclusive, IntervalBatch};
+/// let batch: IntervalBatch<_, _, _> = [
+///     Inclusive.at(0).to(Exclusive.at(5)),    // [0, 5)
+///     Inclusive.at(3).to(Exclusive.at(8)),    // [3, 8)
+///     Inclusive.at(6).to(Exclusive.at(9)),    // [6, 9)
+/// ]
+/// .into_iter()
+/// .collect();
+/// assert_eq!(batch.len(), 3);
+/// assert_eq!(batch.query_contains(&4), vec![0, 1]);
+/// assert_eq!(batch.query_contains(&5), vec![1]);
+/// assert_eq!(batch.query_overlaps(&Inclusive.at(8).to(Exclusive.at(10))), vec![2]);
+/// assert_eq!(batch.get(1), Some(Inclusive.at(3).to(Exclusive.at(8))));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalBatch<T, L = Inclusive, R = L> {
+    infs: Vec<T>,
+    sups: Vec<T>,
+    lefts: Vec<L>,
+    rights: Vec<R>,
+}
+
+impl<T, L, R> Default for IntervalBatch<T, L, R> {
+    fn default() -> Self {
+        Self {
+            infs: Vec::new(),
+            sups: Vec::new(),
+            lefts: Vec::new(),
+            rights: Vec::new(),
+        }
+    }
+}
+
+impl<T, L, R> IntervalBatch<T, L, R> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn len(&self) -> usize {
+        self.infs.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.infs.is_empty()
+    }
+    pub fn infs(&self) -> &[T] {
+        &self.infs
+    }
+    pub fn sups(&self) -> &[T] {
+        &self.sups
+    }
+    pub fn push(&mut self, item: Interval<T, L, R>) {
+        let (left, right) = (item.left.0, item.right.0);
+        self.infs.push(left.limit);
+        self.sups.push(right.limit);
+        self.lefts.push(left.bound_type);
+        self.rights.push(right.bound_type);
+    }
+}
+
+impl<T: Clone, L: Copy, R: Copy> IntervalBatch<T, L, R> {
+    pub fn get(&self, i: usize) -> Option<Interval<T, L, R>> {
+        (i < self.len()).then(|| {
+            Interval::new_unchecked(
+                Bound::new(self.infs[i].clone(), self.lefts[i]),
+                Bound::new(self.sups[i].clone(), self.rights[i]),
+            )
+        })
+    }
+    pub fn iter(&self) -> impl Iterator<Item = Interval<T, L, R>> + '_ {
+        (0..self.len()).filter_map(|i| self.get(i))
+    }
+}
+
+impl<T: PartialOrd, L: BoundaryOf<Left>, R: BoundaryOf<Right>> IntervalBatch<T, L, R> {
+    /// Indices of the intervals containing `t`, in ascending order.
+    pub fn query_contains(&self, t: &T) -> Vec<usize> {
+        self.indices_where(|inf, sup, left, right| left.less(inf, t) && right.less(t, sup))
+    }
+
+    /// Indices of the intervals overlapping `query`, in ascending order.
+    /// ```
+    /// use inter_val::{BoundType, IntervalBatch};
+    /// let (incl, excl) = (BoundType::Inclusive, BoundType::Exclusive);
+    /// let batch: IntervalBatch<_, _, _> = [
+    ///     incl.at(0.0).to(excl.at(1.0)),  // [0, 1)
+    ///     incl.at(1.0).to(incl.at(2.0)),  // [1, 2]
+    ///     excl.at(2.0).to(incl.at(3.0)),  // (2, 3]
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// assert_eq!(batch.query_overlaps(&incl.at(1.0).to(incl.at(2.0))), vec![1]);
+    /// assert_eq!(batch.query_overlaps(&incl.at(0.5).to(excl.at(2.5))), vec![0, 1, 2]);
+    /// ```
+    pub fn query_overlaps(&self, query: &Interval<T, L, R>) -> Vec<usize> {
+        let (q_left, q_right) = (&query.left.0, &query.right.0);
+        self.indices_where(|inf, sup, left, right| {
+            left.less(inf, &q_right.limit)
+                && q_right.bound_type.less(inf, &q_right.limit)
+                && right.less(&q_left.limit, sup)
+                && q_left.bound_type.less(&q_left.limit, sup)
+        })
+    }
+
+    fn indices_where(&self, f: impl Fn(&T, &T, &L, &R) -> bool) -> Vec<usize> {
+        let items = self
+            .infs
+            .iter()
+            .zip(&self.sups)
+            .zip(&self.lefts)
+            .zip(&self.rights);
+        items
+            .enumerate()
+            .filter(|(_, (((inf, sup), left), right))| f(inf, sup, left, right))
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+impl<T, L, R> Extend<Interval<T, L, R>> for IntervalBatch<T, L, R> {
+    fn extend<I: IntoIterator<Item = Interval<T, L, R>>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T, L, R> FromIterator<Interval<T, L, R>> for IntervalBatch<T, L, R> {
+    fn from_iter<I: IntoIterator<Item = Interval<T, L, R>>>(iter: I) -> Self {
+        let mut batch = Self::new();
+        batch.extend(iter);
+        batch
+    }
+}
@@ -2,45 +2,60 @@ use crate::bound_type::{Left, Right};
 use crate::ndim::NDim;
 use crate::traits::BoundaryOf;
 use crate::{Bound, Exclusive, Inclusive, Interval};
+use alloc::vec::Vec;
 
 pub trait Point<const N: usize, T>:
-    From<[T; N]> + Into<[T; N]> + std::ops::Index<usize, Output = T>
+    From<[T; N]> + Into<[T; N]> + core::ops::Index<usize, Output = T>
 {
-    fn iter(&self) -> std::slice::Iter<T>;
+    fn iter(&self) -> core::slice::Iter<'_, T>;
 }
 
 impl<const N: usize, T> Point<N, T> for [T; N] {
-    fn iter(&self) -> std::slice::Iter<T> {
+    fn iter(&self) -> core::slice::Iter<'_, T> {
         (self as &[T]).iter()
     }
 }
 
 impl<const N: usize, T> Point<N, T> for NDim<N, T> {
-    fn iter(&self) -> std::slice::Iter<T> {
+    fn iter(&self) -> core::slice::Iter<'_, T> {
         self.iter()
     }
 }
 
 #[cfg(feature = "nalgebra")]
-impl<const N: usize, T: Clone + std::fmt::Debug + PartialEq + 'static> Point<N, T>
+impl<const N: usize, T: Clone + core::fmt::Debug + PartialEq + 'static> Point<N, T>
     for nalgebra::Point<T, N>
 {
-    fn iter(&self) -> std::slice::Iter<T> {
+    fn iter(&self) -> core::slice::Iter<'_, T> {
         self.coords.as_slice().iter()
     }
 }
 
+#[cfg(feature = "glam")]
+impl Point<2, f32> for glam::Vec2 {
+    fn iter(&self) -> core::slice::Iter<'_, f32> {
+        self.as_ref().iter()
+    }
+}
+
+#[cfg(feature = "glam")]
+impl Point<3, f32> for glam::Vec3 {
+    fn iter(&self) -> core::slice::Iter<'_, f32> {
+        self.as_ref().iter()
+    }
+}
+
 /// n-dimensional axis-aligned box as a cartesian product set of intervals, i.g., *[a, b)^n*.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct BoxN<const N: usize, T, L = Inclusive, R = L>(NDim<N, Interval<T, L, R>>);
 
-impl<const N: usize, T, L, R> std::ops::Deref for BoxN<N, T, L, R> {
+impl<const N: usize, T, L, R> core::ops::Deref for BoxN<N, T, L, R> {
     type Target = NDim<N, Interval<T, L, R>>;
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
-impl<const N: usize, T, L, R> std::ops::DerefMut for BoxN<N, T, L, R> {
+impl<const N: usize, T, L, R> core::ops::DerefMut for BoxN<N, T, L, R> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
@@ -71,6 +86,77 @@ impl<const N: usize, T, L, R> BoxN<N, T, L, R> {
     pub fn into_array(self) -> [Interval<T, L, R>; N] {
         self.into()
     }
+
+    /// Interval of the `i`-th axis. Panics if `i >= N`.
+    /// ```
+    /// use inter_val::{Box2, Inclusive};
+    /// let a: Box2<i32> = Box2::between(&[0, 0], &[10, 20]);
+    /// assert_eq!(a.axis(0), &Inclusive.between(0, 10));
+    /// assert_eq!(a.axis(1), &Inclusive.between(0, 20));
+    /// ```
+    pub fn axis(&self, i: usize) -> &Interval<T, L, R> {
+        &self.0.as_array()[i]
+    }
+
+    /// Mutable interval of the `i`-th axis. Panics if `i >= N`.
+    /// ```
+    /// use inter_val::{Box2, Inclusive};
+    /// let mut a: Box2<i32> = Box2::between(&[0, 0], &[10, 20]);
+    /// *a.axis_mut(0) = Inclusive.between(5, 6);
+    /// assert_eq!(a, Box2::between(&[5, 0], &[6, 20]));
+    /// ```
+    pub fn axis_mut(&mut self, i: usize) -> &mut Interval<T, L, R> {
+        &mut self.0.as_array_mut()[i]
+    }
+
+    /// Replace the interval of the `i`-th axis. Panics if `i >= N`.
+    /// ```
+    /// use inter_val::{Box2, Inclusive};
+    /// let a: Box2<i32> = Box2::between(&[0, 0], &[10, 20]);
+    /// let b = a.with_axis(1, Inclusive.between(-5, 5));
+    /// assert_eq!(b, Box2::between(&[0, -5], &[10, 5]));
+    /// assert_eq!(b.axis(0), a.axis(0));
+    /// ```
+    pub fn with_axis(mut self, i: usize, item: Interval<T, L, R>) -> Self {
+        *self.axis_mut(i) = item;
+        self
+    }
+}
+
+impl<const N: usize, T: PartialOrd> BoxN<N, T, Inclusive, Exclusive> {
+    /// Construct from half-open ranges of the axes. Return `None` if any of them is empty.
+    /// ```
+    /// use inter_val::{Box2, Inclusive, Exclusive};
+    /// let a = Box2::from_ranges([0..10, 5..20]).unwrap();
+    /// assert_eq!(a, Box2::new(Inclusive.at(0).to(Exclusive.at(10)), Inclusive.at(5).to(Exclusive.at(20))));
+    /// assert!(Box2::from_ranges([0..10, 5..5]).is_none());
+    /// ```
+    pub fn from_ranges(ranges: [core::ops::Range<T>; N]) -> Option<Self> {
+        let mut tmp = ranges.map(|r| Interval::try_from(r).ok());
+        tmp.iter()
+            .all(|i| i.is_some())
+            .then(|| core::array::from_fn(|i| tmp[i].take().unwrap()).into())
+    }
+}
+
+impl<const N: usize, T: PartialOrd + Clone> BoxN<N, T, Inclusive, Exclusive> {
+    /// Split into the lower and upper boxes along `axis` at `value` by `Interval::try_split_at()`.
+    /// Return `None` unless `value` is in the interior of the `axis`-th interval, i.e., both boxes are non-empty.
+    /// ```
+    /// use inter_val::Box2;
+    /// let a = Box2::from_ranges([0..10, 0..4]).unwrap();
+    /// let (lower, upper) = a.split_axis_at(0, 3).unwrap();
+    /// assert_eq!(lower, Box2::from_ranges([0..3, 0..4]).unwrap());
+    /// assert_eq!(upper, Box2::from_ranges([3..10, 0..4]).unwrap());
+    /// assert!(a.split_axis_at(1, 0).is_none());
+    /// ```
+    pub fn split_axis_at(&self, axis: usize, value: T) -> Option<(Self, Self)> {
+        let (lower, upper) = self[axis].try_split_at(value);
+        Some((
+            self.clone().with_axis(axis, lower?),
+            self.clone().with_axis(axis, upper?),
+        ))
+    }
 }
 
 impl<T, L, R> BoxN<2, T, L, R> {
@@ -110,10 +196,10 @@ impl<const N: usize, T: PartialOrd + Clone, L: BoundaryOf<Left>, R: BoundaryOf<R
         T: Into<Bound<T, L>> + Into<Bound<T, R>>,
     {
         let mut tmp: [_; N] =
-            std::array::from_fn(|i| Interval::try_between(a[i].clone(), b[i].clone()));
+            core::array::from_fn(|i| Interval::try_between(a[i].clone(), b[i].clone()));
         tmp.iter()
             .all(|i| i.is_some())
-            .then(|| std::array::from_fn(|i| tmp[i].take().unwrap()).into())
+            .then(|| core::array::from_fn(|i| tmp[i].take().unwrap()).into())
     }
 
     /// ```
@@ -125,29 +211,79 @@ impl<const N: usize, T: PartialOrd + Clone, L: BoundaryOf<Left>, R: BoundaryOf<R
     where
         T: Into<Bound<T, L>> + Into<Bound<T, R>>,
     {
-        std::array::from_fn(|i| Interval::between(a[i].clone(), b[i].clone())).into()
+        core::array::from_fn(|i| Interval::between(a[i].clone(), b[i].clone())).into()
+    }
+
+    /// Like `between()` but skips the per-axis emptiness check, via `Interval::new_unchecked()`.
+    /// `a[i]` must be less than `b[i]` for each axis, or equal to it if both bounds are inclusive; they are not swapped.
+    /// The check is still performed by `debug_assert!` in debug builds.
+    /// ```
+    /// use inter_val::Box2;
+    /// let a: Box2<i32> = Box2::between_unchecked(&[0, 0], &[10, 20]);
+    /// assert_eq!(a, Box2::between(&[0, 0], &[10, 20]));
+    /// ```
+    pub fn between_unchecked<P: Point<N, T>>(a: &P, b: &P) -> Self
+    where
+        T: Into<Bound<T, L>> + Into<Bound<T, R>>,
+    {
+        core::array::from_fn(|i| {
+            let item = Interval::new_unchecked(a[i].clone().into(), b[i].clone().into());
+            debug_assert!(
+                crate::interval::is_valid_interval(item.left(), item.right()),
+                "Invalid interval: left must be less than right."
+            );
+            item
+        })
+        .into()
     }
 
     pub fn inf(&self) -> NDim<N, T> {
-        std::array::from_fn(|i| self[i].inf().clone()).into()
+        core::array::from_fn(|i| self[i].inf().clone()).into()
     }
 
     pub fn sup(&self) -> NDim<N, T> {
-        std::array::from_fn(|i| self[i].sup().clone()).into()
+        core::array::from_fn(|i| self[i].sup().clone()).into()
+    }
+
+    /// All the `2^N` corners. Bit `i` of the index of a corner tells whether its coordinate on axis `i` is `inf` (0) or `sup` (1).
+    /// Returned as `Vec` since `[NDim<N, T>; 1 << N]` is not expressible with a generic `N` on stable Rust.
+    /// ```
+    /// use inter_val::{Box2, Box3};
+    /// let a: Box2<i32> = Box2::between(&[0, 0], &[10, 20]);
+    /// let corners: Vec<[i32; 2]> = a.corners().into_iter().map(|p| p.into_array()).collect();
+    /// assert_eq!(corners, vec![[0, 0], [10, 0], [0, 20], [10, 20]]);
+    ///
+    /// let b: Box3<f64> = Box3::between(&[0.0, 0.0, 0.0], &[1.0, 1.0, 1.0]);
+    /// assert_eq!(b.corners().len(), 8);
+    /// assert_eq!(b.corners()[0b101].into_array(), [1.0, 0.0, 1.0]);
+    /// ```
+    pub fn corners(&self) -> Vec<NDim<N, T>> {
+        (0..1usize << N)
+            .map(|bits| {
+                core::array::from_fn(|i| {
+                    if bits & (1 << i) == 0 {
+                        self[i].inf().clone()
+                    } else {
+                        self[i].sup().clone()
+                    }
+                })
+                .into()
+            })
+            .collect()
     }
 
     pub fn inf_point<P: Point<N, T>>(&self) -> P {
-        std::array::from_fn(|i| self[i].inf().clone()).into()
+        core::array::from_fn(|i| self[i].inf().clone()).into()
     }
 
     pub fn sup_point<P: Point<N, T>>(&self) -> P {
-        std::array::from_fn(|i| self[i].sup().clone()).into()
+        core::array::from_fn(|i| self[i].sup().clone()).into()
     }
 
     #[cfg(feature = "nalgebra")]
     pub fn inf_nalgebra(&self) -> nalgebra::Point<T, N>
     where
-        T: Clone + std::fmt::Debug + PartialEq + 'static,
+        T: Clone + core::fmt::Debug + PartialEq + 'static,
     {
         self.inf_point()
     }
@@ -155,15 +291,44 @@ impl<const N: usize, T: PartialOrd + Clone, L: BoundaryOf<Left>, R: BoundaryOf<R
     #[cfg(feature = "nalgebra")]
     pub fn sup_nalgebra(&self) -> nalgebra::Point<T, N>
     where
-        T: Clone + std::fmt::Debug + PartialEq + 'static,
+        T: Clone + core::fmt::Debug + PartialEq + 'static,
     {
         self.sup_point()
     }
 
+    #[cfg(feature = "nalgebra")]
+    pub fn from_nalgebra_corners(min: nalgebra::Point<T, N>, max: nalgebra::Point<T, N>) -> Self
+    where
+        T: Clone + core::fmt::Debug + PartialEq + 'static + Into<Bound<T, L>> + Into<Bound<T, R>>,
+    {
+        Self::between(&min, &max)
+    }
+
+    #[cfg(feature = "nalgebra")]
+    pub fn to_nalgebra_corners(&self) -> (nalgebra::Point<T, N>, nalgebra::Point<T, N>)
+    where
+        T: Clone + core::fmt::Debug + PartialEq + 'static,
+    {
+        (self.inf_nalgebra(), self.sup_nalgebra())
+    }
+
     pub fn contains<P: Point<N, T>>(&self, t: &P) -> bool {
         self.iter().zip(t.iter()).all(|(i, t)| i.contains(t))
     }
 
+    /// Whether `t` is in the interior of the box regardless of the bound types. See `Interval::contains_interior()`.
+    /// ```
+    /// use inter_val::Box2;
+    /// let a: Box2<i32> = Box2::between(&[0, 0], &[4, 2]);
+    /// assert!(a.contains(&[0, 1]) && !a.contains_interior(&[0, 1]));
+    /// assert!(a.contains_interior(&[1, 1]));
+    /// ```
+    pub fn contains_interior<P: Point<N, T>>(&self, t: &P) -> bool {
+        self.iter()
+            .zip(t.iter())
+            .all(|(i, t)| i.contains_interior(t))
+    }
+
     pub fn includes(&self, other: &Self) -> bool {
         self.iter().zip(other.iter()).all(|(i, o)| i.includes(o))
     }
@@ -173,32 +338,91 @@ impl<const N: usize, T: PartialOrd + Clone, L: BoundaryOf<Left>, R: BoundaryOf<R
     }
 
     pub fn closure(&self) -> BoxN<N, T, Inclusive> {
-        std::array::from_fn(|i| self[i].clone().closure()).into()
+        core::array::from_fn(|i| self[i].clone().closure()).into()
     }
 
     pub fn interior(&self) -> Option<BoxN<N, T, Exclusive>> {
-        let mut tmp: [_; N] = std::array::from_fn(|i| self[i].clone().interior());
+        let mut tmp: [_; N] = core::array::from_fn(|i| self[i].clone().interior());
         tmp.iter()
             .all(|i| i.is_some())
-            .then(|| std::array::from_fn(|i| tmp[i].take().unwrap()).into())
+            .then(|| core::array::from_fn(|i| tmp[i].take().unwrap()).into())
     }
 
     pub fn intersection(&self, other: &Self) -> Option<Self> {
-        let mut tmp: [_; N] = std::array::from_fn(|i| self[i].intersection(&other[i]));
+        let mut tmp: [_; N] = core::array::from_fn(|i| self[i].intersection(&other[i]));
         tmp.iter()
             .all(|i| i.is_some())
-            .then(|| std::array::from_fn(|i| tmp[i].take().unwrap()).into())
+            .then(|| core::array::from_fn(|i| tmp[i].take().unwrap()).into())
+    }
+
+    /// Crop `self` to lie within `bounds`, i.e., the same as `intersection()`. Return `None` if `self` is entirely outside `bounds`.
+    /// ```
+    /// use inter_val::Box2;
+    /// let viewport: Box2<i32> = Box2::between(&[0, 0], &[100, 50]);
+    /// let a: Box2<i32> = Box2::between(&[80, -10], &[120, 20]);
+    /// assert_eq!(a.crop_to(&viewport), Some(Box2::between(&[80, 0], &[100, 20])));
+    /// ```
+    pub fn crop_to(&self, bounds: &Self) -> Option<Self> {
+        self.intersection(bounds)
     }
 
     pub fn span(&self, other: &Self) -> Self {
-        std::array::from_fn(|i| self[i].clone().span(&other[i])).into()
+        core::array::from_fn(|i| self[i].clone().span(&other[i])).into()
     }
 
     pub fn dilate(&self, delta: T) -> Self
     where
-        T: std::ops::Add<Output = T> + std::ops::Sub<Output = T>,
+        T: core::ops::Add<Output = T> + core::ops::Sub<Output = T>,
+    {
+        core::array::from_fn(|i| self[i].clone().dilate(delta.clone())).into()
+    }
+
+    /// Dilate each axis `i` by `deltas[i]`. Panics if any axis becomes empty.
+    /// ```
+    /// use inter_val::Box2;
+    /// let a: Box2<i32> = Box2::between(&[0, 0], &[10, 10]);
+    /// assert_eq!(a.dilate_each(&[2, 5]), Box2::between(&[-2, -5], &[12, 15]));
+    /// ```
+    pub fn dilate_each<P: Point<N, T>>(&self, deltas: &P) -> Self
+    where
+        T: core::ops::Add<Output = T> + core::ops::Sub<Output = T>,
     {
-        std::array::from_fn(|i| self[i].clone().dilate(delta.clone())).into()
+        core::array::from_fn(|i| self[i].clone().dilate(deltas[i].clone())).into()
+    }
+
+    /// Same as `dilate_each()` but return `None` if any axis becomes empty.
+    /// ```
+    /// use inter_val::Box2;
+    /// let a: Box2<i32> = Box2::between(&[0, 0], &[10, 10]);
+    /// assert_eq!(a.try_dilate_each(&[2, -5]), Some(Box2::between(&[-2, 5], &[12, 5])));
+    /// assert_eq!(a.try_dilate_each(&[2, -6]), None);
+    /// ```
+    pub fn try_dilate_each<P: Point<N, T>>(&self, deltas: &P) -> Option<Self>
+    where
+        T: core::ops::Add<Output = T> + core::ops::Sub<Output = T>,
+    {
+        let mut tmp: [_; N] =
+            core::array::from_fn(|i| self[i].clone().try_dilate(deltas[i].clone()));
+        tmp.iter()
+            .all(|i| i.is_some())
+            .then(|| core::array::from_fn(|i| tmp[i].take().unwrap()).into())
+    }
+
+    /// Move the box by `offset` preserving the size and the bound types.
+    /// `offset` can be any `Point`, e.g., `NDim` or an array.
+    /// ```
+    /// use inter_val::{Box2, NDim};
+    /// let a: Box2<i32> = Box2::between(&[0, 0], &[10, 20]);
+    /// let b = a.translate(&NDim::from([5, -5]));
+    /// assert_eq!(b, Box2::between(&[5, -5], &[15, 15]));
+    /// assert_eq!(b.size(), a.size());
+    /// assert_eq!(a.translate(&[1, 2]), Box2::between(&[1, 2], &[11, 22]));
+    /// ```
+    pub fn translate<P: Point<N, T>>(&self, offset: &P) -> Self
+    where
+        T: core::ops::Add<Output = T>,
+    {
+        core::array::from_fn(|i| self[i].clone().map(|t| t + offset[i].clone())).into()
     }
 
     /// ```
@@ -208,7 +432,43 @@ impl<const N: usize, T: PartialOrd + Clone, L: BoundaryOf<Left>, R: BoundaryOf<R
     /// assert_eq!(b, Box2::between(&[0, 0], &[20, 10]));
     /// ```
     pub fn hull<P: Point<N, T>>(self, p: &P) -> Self {
-        std::array::from_fn(|i| self[i].clone().hull(p[i].clone())).into()
+        core::array::from_fn(|i| self[i].clone().hull(p[i].clone())).into()
+    }
+
+    /// Nearest point of the closure of the box to `p`, i.e., `Interval::clamp()` applied to each coordinate.
+    /// ```
+    /// use inter_val::Box2;
+    /// let a: Box2<f64> = Box2::between(&[0.0, 0.0], &[4.0, 2.0]);
+    /// assert_eq!(a.closest_point(&[-1.0, 5.0]).into_array(), [0.0, 2.0]);
+    /// assert_eq!(a.closest_point(&[1.0, 1.0]).into_array(), [1.0, 1.0]);
+    /// ```
+    pub fn closest_point<P: Point<N, T>>(&self, p: &P) -> NDim<N, T> {
+        core::array::from_fn(|i| self[i].clamp(p[i].clone())).into()
+    }
+
+    /// In-place version of `span()`.
+    /// ```
+    /// use inter_val::Box2;
+    /// let mut a: Box2<i32> = Box2::between(&[0, 0], &[1, 1]);
+    /// a.expand_to_include(&Box2::between(&[5, -2], &[6, 0]));
+    /// assert_eq!(a, Box2::between(&[0, -2], &[6, 1]));
+    /// ```
+    pub fn expand_to_include(&mut self, other: &Self) {
+        *self = self.span(other);
+    }
+
+    /// In-place version of `hull()`.
+    /// ```
+    /// use inter_val::Box2;
+    /// let points = [[3, 1], [-2, 4], [0, 0], [5, -1]];
+    /// let mut a: Box2<i32> = Box2::between(&points[0], &points[0]);
+    /// for p in &points[1..] {
+    ///     a.expand_to_include_point(p);
+    /// }
+    /// assert_eq!(a, Box2::between(&[-2, -1], &[5, 4]));
+    /// ```
+    pub fn expand_to_include_point<P: Point<N, T>>(&mut self, p: &P) {
+        *self = self.clone().hull(p);
     }
 
     pub fn span_many<A: Into<Self>>(items: impl IntoIterator<Item = A>) -> Option<Self> {
@@ -217,6 +477,20 @@ impl<const N: usize, T: PartialOrd + Clone, L: BoundaryOf<Left>, R: BoundaryOf<R
         Some(items.fold(first, |acc, item| acc.span(&item.into())))
     }
 
+    /// The smallest box containing all of `items`, i.e., `Interval::enclosure()` for each axis. Return `None` if `items` is empty.
+    /// ```
+    /// use inter_val::Box2;
+    /// let items: Vec<Box2<i32>> = vec![
+    ///     Box2::between(&[0, 0], &[2, 2]),
+    ///     Box2::between(&[1, -3], &[3, 1]),
+    ///     Box2::between(&[10, 5], &[11, 6]),
+    /// ];
+    /// assert_eq!(Box2::enclosure(items), Some(Box2::between(&[0, -3], &[11, 6])));
+    /// ```
+    pub fn enclosure(items: impl IntoIterator<Item = Self>) -> Option<Self> {
+        items.into_iter().reduce(|a, b| a.span(&b))
+    }
+
     pub fn hull_many<'a>(items: impl IntoIterator<Item = &'a [T; N]>) -> Option<Self>
     where
         T: Clone + Into<Bound<T, L>> + Into<Bound<T, R>> + 'a,
@@ -244,18 +518,222 @@ where
     R: BoundaryOf<Right>,
 {
     pub fn size(&self) -> NDim<N, T> {
-        std::array::from_fn(|i| self[i].measure()).into()
+        core::array::from_fn(|i| self[i].measure()).into()
     }
     pub fn measure(&self) -> T {
         self.iter()
             .map(|item| item.measure())
             .fold(T::one(), |a, b| a * b)
     }
+
+    /// Measure of the intersection, or zero if `self` and `other` are disjoint.
+    /// ```
+    /// use inter_val::Box2;
+    /// let a: Box2<i32> = Box2::between(&[0, 0], &[4, 3]);
+    /// let b: Box2<i32> = Box2::between(&[2, 1], &[6, 6]);
+    /// let c: Box2<i32> = Box2::between(&[5, 0], &[6, 3]);
+    /// assert_eq!(a.overlap_measure(&b), 2 * 2);
+    /// assert_eq!(a.overlap_measure(&c), 0);
+    /// ```
+    pub fn overlap_measure(&self, other: &Self) -> T {
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| a.overlap_measure(b))
+            .fold(T::one(), |a, b| a * b)
+    }
+
+    /// Sum of the products of all but one axis lengths, i.e., half of the surface area in 3D, and half of the perimeter in 2D.
+    /// ```
+    /// use inter_val::{Box2, Box3};
+    /// let a: Box2<i32> = Box2::between(&[0, 0], &[2, 3]);
+    /// assert_eq!(a.half_surface_area(), 5);
+    /// let b: Box3<i32> = Box3::between(&[0, 0, 0], &[2, 3, 4]);
+    /// assert_eq!(b.half_surface_area(), 2 * 3 + 3 * 4 + 4 * 2);
+    /// ```
+    pub fn half_surface_area(&self) -> T {
+        let size = self.size();
+        (0..N)
+            .map(|i| {
+                (0..N)
+                    .filter(|&j| j != i)
+                    .fold(T::one(), |acc, j| acc * size[j].clone())
+            })
+            .fold(T::zero(), |a, b| a + b)
+    }
+}
+
+impl<T, L, R> BoxN<2, T, L, R>
+where
+    T: PartialOrd + Clone + num::Num,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+{
+    /// ```
+    /// use inter_val::Box2;
+    /// let a: Box2<f64> = Box2::between(&[0.0, 0.0], &[2.0, 3.0]);
+    /// assert_eq!(a.perimeter(), 10.0);
+    /// ```
+    pub fn perimeter(&self) -> T {
+        let half = self.half_surface_area();
+        half.clone() + half
+    }
+}
+
+impl<T, L, R> BoxN<3, T, L, R>
+where
+    T: PartialOrd + Clone + num::Num,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+{
+    /// ```
+    /// use inter_val::Box3;
+    /// let a: Box3<f64> = Box3::between(&[0.0, 0.0, 0.0], &[2.0, 3.0, 4.0]);
+    /// assert_eq!(a.surface_area(), 52.0);
+    /// ```
+    pub fn surface_area(&self) -> T {
+        let half = self.half_surface_area();
+        half.clone() + half
+    }
 }
 
+impl<const N: usize, T, L, R> BoxN<N, T, L, R>
+where
+    T: num::Integer + Clone,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+{
+    /// Lazily iterate all the integer lattice points in the box, where the first axis varies fastest.
+    /// ```
+    /// use inter_val::{Box2, Inclusive, Exclusive, NDim};
+    /// let a = Box2::new(Inclusive.at(0).to(Exclusive.at(3)), Inclusive.at(10).to(Exclusive.at(12)));
+    /// let cells: Vec<[i32; 2]> = a.cells().map(NDim::into_array).collect();
+    /// assert_eq!(cells, vec![[0, 10], [1, 10], [2, 10], [0, 11], [1, 11], [2, 11]]);
+    ///
+    /// let b = Box2::new(Exclusive.at(0).to(Exclusive.at(1)), Exclusive.at(0).to(Exclusive.at(5)));
+    /// assert_eq!(b.cells().count(), 0);  // No integer in (0, 1).
+    /// ```
+    pub fn cells(&self) -> impl Iterator<Item = NDim<N, T>> {
+        let ranges: [(T, T); N] = core::array::from_fn(|i| self[i].integer_bounds());
+        let mut current = (!ranges.iter().any(|(first, last)| first > last))
+            .then(|| core::array::from_fn::<T, N, _>(|i| ranges[i].0.clone()));
+        core::iter::from_fn(move || {
+            let cell = current.clone()?;
+            let next = current.as_mut().unwrap();
+            let carried = (0..N).all(|i| {
+                if next[i] < ranges[i].1 {
+                    next[i] = next[i].clone() + T::one();
+                    false
+                } else {
+                    next[i] = ranges[i].0.clone();
+                    true
+                }
+            });
+            if carried {
+                current = None;
+            }
+            Some(cell.into())
+        })
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
 impl<const N: usize, T: num::Float, L: BoundaryOf<Left>, R: BoundaryOf<Right>> BoxN<N, T, L, R> {
     pub fn center(&self) -> NDim<N, T> {
-        std::array::from_fn(|i| self[i].center()).into()
+        core::array::from_fn(|i| self[i].center()).into()
+    }
+
+    /// Scale each axis about the corresponding coordinate of `anchor` by `factor`.
+    /// ```
+    /// use inter_val::Box2;
+    /// let a: Box2<f64> = Box2::between(&[0.0, 0.0], &[10.0, 4.0]);
+    /// let b = a.scale_about(&[2.0, 1.0], 2.0);
+    /// assert_eq!(b, Box2::between(&[-2.0, -1.0], &[18.0, 7.0]));
+    /// ```
+    pub fn scale_about<P: Point<N, T>>(&self, anchor: &P, factor: T) -> Self {
+        core::array::from_fn(|i| self[i].scale_about(anchor[i], factor)).into()
+    }
+
+    /// Split each axis at its center into `2^N` children, e.g., quadrants for `Box2` and octants for `Box3`.
+    /// Bit `i` of the index of a child tells whether the child is the lower (0) or the upper (1) half on axis `i`.
+    /// The children tile the parent exactly if the box is half-open, i.e., `BoxN<N, T, Inclusive, Exclusive>`.
+    /// Returned as `Vec` since `[Self; 1 << N]` is not expressible with a generic `N` on stable Rust.
+    /// ```
+    /// use inter_val::{Box2, Inclusive, Exclusive};
+    /// let a = Box2::new(Inclusive.at(0.0).to(Exclusive.at(4.0)), Inclusive.at(0.0).to(Exclusive.at(2.0)));
+    /// let children = a.subdivide();
+    /// assert_eq!(children.len(), 4);
+    /// assert_eq!(children[0], Box2::new(Inclusive.at(0.0).to(Exclusive.at(2.0)), Inclusive.at(0.0).to(Exclusive.at(1.0))));
+    /// assert_eq!(children[1], Box2::new(Inclusive.at(2.0).to(Exclusive.at(4.0)), Inclusive.at(0.0).to(Exclusive.at(1.0))));
+    /// assert_eq!(children[2], Box2::new(Inclusive.at(0.0).to(Exclusive.at(2.0)), Inclusive.at(1.0).to(Exclusive.at(2.0))));
+    /// assert_eq!(children[3], Box2::new(Inclusive.at(2.0).to(Exclusive.at(4.0)), Inclusive.at(1.0).to(Exclusive.at(2.0))));
+    /// assert_eq!(children.iter().map(|c| c.measure()).sum::<f64>(), a.measure());
+    /// ```
+    pub fn subdivide(&self) -> Vec<Self> {
+        (0..1usize << N)
+            .map(|bits| {
+                core::array::from_fn(|i| {
+                    let item = &self[i];
+                    let center = item.center();
+                    let (left, right) = if bits & (1 << i) == 0 {
+                        let right = Bound {
+                            limit: center,
+                            bound_type: item.right.bound_type,
+                        };
+                        (item.left, right.into())
+                    } else {
+                        let left = Bound {
+                            limit: center,
+                            bound_type: item.left.bound_type,
+                        };
+                        (left.into(), item.right)
+                    };
+                    Interval::new_(left, right).expect("subdivided interval must not be empty")
+                })
+                .into()
+            })
+            .collect()
+    }
+
+    /// Parameter interval *[t_near, t_far]* of the ray *origin + t * dir (t ≥ 0)* inside the closure of the box, by the slab method.
+    /// Return `None` if the ray misses the box. Zero components of `dir` are handled without division.
+    /// ```
+    /// use inter_val::{Box3, Inclusive};
+    /// let a: Box3<f64> = Box3::between(&[0.0, 0.0, 0.0], &[2.0, 2.0, 2.0]);
+    ///
+    /// // Hit
+    /// let t = a.ray_intersection(&[-1.0, 1.0, 1.0], &[1.0, 0.0, 0.0]).unwrap();
+    /// assert_eq!(t, Inclusive.between(1.0, 3.0));
+    ///
+    /// // Miss
+    /// assert!(a.ray_intersection(&[-1.0, 3.0, 1.0], &[1.0, 0.0, 0.0]).is_none());
+    /// assert!(a.ray_intersection(&[-1.0, 1.0, 1.0], &[-1.0, 0.0, 0.0]).is_none()); // The box is behind.
+    ///
+    /// // Origin inside
+    /// let t = a.ray_intersection(&[1.0, 1.0, 1.0], &[0.0, 0.0, -0.5]).unwrap();
+    /// assert_eq!(t, Inclusive.between(0.0, 2.0));
+    /// ```
+    pub fn ray_intersection<P: Point<N, T>>(
+        &self,
+        origin: &P,
+        dir: &P,
+    ) -> Option<Interval<T, Inclusive>> {
+        let (mut near, mut far) = (T::zero(), T::infinity());
+        for i in 0..N {
+            let (inf, sup) = (*self[i].inf(), *self[i].sup());
+            if dir[i].is_zero() {
+                if origin[i] < inf || sup < origin[i] {
+                    return None;
+                }
+                continue;
+            }
+            let (t0, t1) = ((inf - origin[i]) / dir[i], (sup - origin[i]) / dir[i]);
+            near = near.max(t0.min(t1));
+            far = far.min(t0.max(t1));
+            if far < near {
+                return None;
+            }
+        }
+        Some(Inclusive.at(near).to(Inclusive.at(far)))
     }
 
     /// IoU - Intersection over Union.
@@ -267,6 +745,59 @@ impl<const N: usize, T: num::Float, L: BoundaryOf<Left>, R: BoundaryOf<Right>> B
             })
             .unwrap_or(T::zero())
     }
+
+    /// GIoU - Generalized Intersection over Union, i.e., *IoU - (|C| - |A ∪ B|) / |C|* where *C* is the `span()` of *A* and *B*.
+    /// The value is in *[-1, 1]* and, unlike IoU, keeps decreasing as disjoint boxes move apart.
+    /// A term whose denominator is zero, i.e., for boxes without volume, is taken as zero instead of NaN.
+    /// ```
+    /// use inter_val::Box2;
+    /// let a: Box2<f64> = Box2::between(&[0.0, 0.0], &[2.0, 2.0]);
+    /// let b: Box2<f64> = Box2::between(&[1.0, 1.0], &[3.0, 3.0]);
+    /// assert_eq!(a.giou(&a), 1.0);
+    /// assert_eq!(a.giou(&b), 1.0 / 7.0 - 2.0 / 9.0);
+    ///
+    /// let p: Box2<f64> = Box2::between(&[1.0, 1.0], &[1.0, 1.0]);
+    /// assert_eq!(p.giou(&p), 0.0);
+    /// ```
+    pub fn giou(&self, other: &Self) -> T {
+        let ratio = |n: T, d: T| if d.is_zero() { T::zero() } else { n / d };
+        let intersection = self.overlap_measure(other);
+        let union = self.measure() + other.measure() - intersection;
+        let enclosure = self.span(other).measure();
+        ratio(intersection, union) - ratio(enclosure - union, enclosure)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl<L: BoundaryOf<Left>, R: BoundaryOf<Right>> BoxN<2, f32, L, R> {
+    pub fn from_glam(min: glam::Vec2, max: glam::Vec2) -> Self
+    where
+        f32: Into<Bound<f32, L>> + Into<Bound<f32, R>>,
+    {
+        Self::between(&min, &max)
+    }
+    pub fn center_glam(&self) -> glam::Vec2 {
+        self.center().into_array().into()
+    }
+    pub fn size_glam(&self) -> glam::Vec2 {
+        self.size().into_array().into()
+    }
+}
+
+#[cfg(feature = "glam")]
+impl<L: BoundaryOf<Left>, R: BoundaryOf<Right>> BoxN<3, f32, L, R> {
+    pub fn from_glam(min: glam::Vec3, max: glam::Vec3) -> Self
+    where
+        f32: Into<Bound<f32, L>> + Into<Bound<f32, R>>,
+    {
+        Self::between(&min, &max)
+    }
+    pub fn center_glam(&self) -> glam::Vec3 {
+        self.center().into_array().into()
+    }
+    pub fn size_glam(&self) -> glam::Vec3 {
+        self.size().into_array().into()
+    }
 }
 
 #[cfg(feature = "nalgebra")]
@@ -282,3 +813,193 @@ fn test_nalgebra() {
     let p = na::Point2::new(5, 15);
     assert!(b.contains(&p));
 }
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn test_nalgebra_corners() {
+    use nalgebra as na;
+    let min = na::Point3::new(0.0, -1.0, 2.0);
+    let max = na::Point3::new(1.0, 1.0, 5.0);
+    let b = crate::Box3::<f64>::from_nalgebra_corners(min, max);
+    assert_eq!(b.x, Inclusive.between(0.0, 1.0));
+    assert_eq!(b.y, Inclusive.between(-1.0, 1.0));
+    assert_eq!(b.z, Inclusive.between(2.0, 5.0));
+    assert_eq!(b.to_nalgebra_corners(), (min, max));
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_glam() {
+    use glam::{Vec2, Vec3};
+    let b = crate::Box2::<f32>::from_glam(Vec2::new(0.0, 1.0), Vec2::new(4.0, 3.0));
+    assert!(b.contains(&Vec2::new(2.0, 2.0)));
+    assert!(b.contains(&Vec2::new(4.0, 1.0)));
+    assert!(!b.contains(&Vec2::new(2.0, 0.5)));
+    assert_eq!(b.center_glam(), Vec2::new(2.0, 2.0));
+    assert_eq!(b.size_glam(), Vec2::new(4.0, 2.0));
+    assert_eq!(b.inf_point::<Vec2>(), Vec2::new(0.0, 1.0));
+
+    let b = crate::Box3::<f32>::from_glam(Vec3::ZERO, Vec3::ONE);
+    assert!(b.contains(&Vec3::splat(0.5)));
+    assert_eq!(b.center_glam(), Vec3::splat(0.5));
+}
+
+#[test]
+fn test_cells() {
+    use crate::BoundType;
+    let a = crate::Box2::new(
+        BoundType::Inclusive.at(-3).to(BoundType::Exclusive.at(4)),
+        BoundType::Exclusive.at(2).to(BoundType::Inclusive.at(7)),
+    );
+    assert_eq!(a.cells().count(), 7 * 5);
+    assert!(a.cells().all(|p| a.contains(&p)));
+
+    let b = crate::Box3::<i64>::between(&[0, 0, 0], &[2, 3, 4]);
+    assert_eq!(b.cells().count(), 3 * 4 * 5);
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn test_subdivide() {
+    let a = crate::Box2::new(
+        Inclusive.at(-1.0).to(Exclusive.at(3.0)),
+        Inclusive.at(2.0).to(Exclusive.at(7.0)),
+    );
+    let children = a.subdivide();
+    assert_eq!(children.len(), 4);
+    assert_eq!(
+        children.iter().map(|c| c.measure()).sum::<f64>(),
+        a.measure()
+    );
+    for (i, c) in children.iter().enumerate() {
+        assert!(a.includes(c));
+        for d in &children[i + 1..] {
+            assert!(!c.overlaps(d));
+        }
+    }
+    for p in [[-1.0, 2.0], [1.0, 4.5], [2.9, 6.9], [0.5, 5.0]] {
+        assert_eq!(children.iter().filter(|c| c.contains(&p)).count(), 1);
+    }
+
+    let b = crate::Box3::<f64>::between(&[0.0, 0.0, 0.0], &[2.0, 2.0, 2.0]);
+    assert_eq!(b.subdivide().len(), 8);
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn test_giou() {
+    use crate::Box2;
+    let a: Box2<f64> = Box2::between(&[0.0, 0.0], &[2.0, 2.0]);
+
+    // Overlapping: I = 1, U = 7, C = [0, 3] × [0, 3] = 9.
+    let b: Box2<f64> = Box2::between(&[1.0, 1.0], &[3.0, 3.0]);
+    assert!((a.giou(&b) - (1.0 / 7.0 - 2.0 / 9.0)).abs() < 1e-12);
+    assert_eq!(a.giou(&b), b.giou(&a));
+
+    // Disjoint: I = 0, U = 8, C = [0, 6] × [0, 2] = 12.
+    let c: Box2<f64> = Box2::between(&[4.0, 0.0], &[6.0, 2.0]);
+    assert_eq!(a.iou(&c), 0.0);
+    assert!((a.giou(&c) - (-4.0 / 12.0)).abs() < 1e-12);
+
+    // Farther apart is penalized more.
+    let d: Box2<f64> = Box2::between(&[8.0, 0.0], &[10.0, 2.0]);
+    assert!(a.giou(&d) < a.giou(&c));
+    assert!(a.giou(&d) > -1.0);
+
+    // Contained: I = 1, U = 4, C = a.
+    let e: Box2<f64> = Box2::between(&[0.5, 0.5], &[1.5, 1.5]);
+    assert_eq!(a.giou(&e), a.iou(&e));
+
+    // Degenerate: U = 0 and C = 0 for points, U = 0 and C = 1 for parallel segments.
+    let p: Box2<f64> = Box2::between(&[1.0, 1.0], &[1.0, 1.0]);
+    assert_eq!(p.giou(&p), 0.0);
+    let s: Box2<f64> = Box2::between(&[0.0, 0.0], &[1.0, 0.0]);
+    let t: Box2<f64> = Box2::between(&[0.0, 1.0], &[1.0, 1.0]);
+    assert_eq!(s.giou(&t), -1.0);
+    assert!(!a.giou(&p).is_nan());
+}
+
+#[test]
+fn test_crop_to() {
+    use crate::{Box2, Exclusive, Inclusive};
+    let bounds: Box2<f64, Inclusive, Exclusive> = Box2::between(&[0.0, 0.0], &[4.0, 3.0]);
+    let a = Box2::between(&[-1.0, 1.0], &[2.0, 5.0]);
+    let cropped = a.crop_to(&bounds).unwrap();
+    assert_eq!(cropped, Box2::between(&[0.0, 1.0], &[2.0, 3.0]));
+    assert!(bounds.includes(&cropped));
+    assert!(!cropped.contains(&[1.0, 3.0]));
+
+    let inside = Box2::between(&[1.0, 1.0], &[2.0, 2.0]);
+    assert_eq!(inside.crop_to(&bounds), Some(inside));
+
+    let outside = Box2::between(&[4.0, 0.0], &[6.0, 2.0]);
+    assert_eq!(outside.crop_to(&bounds), None);
+}
+
+#[test]
+fn test_from_ranges() {
+    use crate::{Box2, Box3};
+    let a = Box2::from_ranges([0..10, 5..20]).unwrap();
+    assert!(a.contains(&[0, 5]));
+    assert!(a.contains(&[9, 19]));
+    assert!(!a.contains(&[10, 5]));
+    assert!(!a.contains(&[0, 20]));
+    assert!(!a.contains(&[0, 4]));
+    assert_eq!(a.measure(), 10 * 15);
+
+    let b = Box3::from_ranges([0.0..1.0, -1.0..1.0, 2.0..2.5]).unwrap();
+    assert!(b.contains(&[0.5, 0.0, 2.0]));
+    assert!(!b.contains(&[0.5, 0.0, 2.5]));
+
+    assert!(Box2::from_ranges([0..10, 5..5]).is_none());
+}
+
+#[test]
+fn test_closest_point() {
+    use crate::{Box2, Exclusive, Inclusive};
+    let a: Box2<i32> = Box2::between(&[0, 0], &[4, 2]);
+    let cases = [
+        ([5, 3], [4, 2]),
+        ([-5, 3], [0, 2]),
+        ([-5, -3], [0, 0]),
+        ([5, -3], [4, 0]),
+        ([2, 9], [2, 2]),
+        ([-9, 1], [0, 1]),
+        ([3, 1], [3, 1]),
+    ];
+    for (p, expected) in cases {
+        let q = a.closest_point(&p);
+        assert_eq!(q.into_array(), expected);
+        assert!(a.contains(&q));
+    }
+
+    let b = Box2::new(
+        Inclusive.at(0.0).to(Exclusive.at(1.0)),
+        Inclusive.at(0.0).to(Exclusive.at(1.0)),
+    );
+    assert_eq!(b.closest_point(&[2.0, 0.5]).into_array(), [1.0, 0.5]);
+    assert!(b.closure().contains(&b.closest_point(&[2.0, 0.5])));
+}
+
+#[test]
+fn test_split_axis_at() {
+    use crate::Box2;
+    let a = Box2::from_ranges([0.0..4.0, -1.0..1.0]).unwrap();
+    let (lower, upper) = a.split_axis_at(0, 1.5).unwrap();
+    assert_eq!(lower.x.sup(), upper.x.inf());
+    assert_eq!(lower.y, a.y);
+    assert_eq!(upper.y, a.y);
+    assert!(!lower.overlaps(&upper));
+    assert_eq!(lower.span(&upper), a);
+    assert_eq!(lower.measure() + upper.measure(), a.measure());
+    for p in [[0.0, 0.0], [1.5, 0.0], [3.9, -1.0], [1.4999, 0.5]] {
+        assert!(a.contains(&p));
+        assert!(lower.contains(&p) != upper.contains(&p));
+    }
+
+    let (lower, upper) = a.split_axis_at(1, 0.0).unwrap();
+    assert_eq!(lower.span(&upper), a);
+    assert!(a.split_axis_at(0, 0.0).is_none());
+    assert!(a.split_axis_at(0, 4.0).is_none());
+    assert!(a.split_axis_at(1, 5.0).is_none());
+}
@@ -0,0 +1,217 @@
+use crate::bound_type::{Left, Right};
+use crate::traits::BoundaryOf;
+use crate::{Inclusive, Interval};
+use alloc::vec::Vec;
+
+/// Set of disjoint intervals, i.e., a union of intervals normalized into a sorted `Vec` of non-overlapping and non-adjacent intervals.
+/// ```
+/// use inter_val::{Inclusive, Exclusive, IntervalSet};
+/// let mut a = IntervalSet::new();
+/// a.insert(Inclusive.at(0).to(Exclusive.at(3)));  // [0, 3)
+/// a.insert(Inclusive.at(8).to(Exclusive.at(10))); // [8, 10)
+/// a.insert(Inclusive.at(3).to(Exclusive.at(5)));  // [3, 5) touches [0, 3)
+/// assert_eq!(
+///     a.iter().cloned().collect::<Vec<_>>(),
+///     vec![Inclusive.at(0).to(Exclusive.at(5)), Inclusive.at(8).to(Exclusive.at(10))]
+/// );
+/// assert_eq!(a.measure(), 7);
+/// assert!(a.contains(&4));
+/// assert!(!a.contains(&5));
+///
+/// let b: IntervalSet<_, _, _> = [Inclusive.at(2).to(Exclusive.at(9))].into_iter().collect();
+/// assert_eq!(
+///     a.union(&b).into_vec(),
+///     vec![Inclusive.at(0).to(Exclusive.at(10))]
+/// );
+/// assert_eq!(
+///     a.intersection(&b).into_vec(),
+///     vec![Inclusive.at(2).to(Exclusive.at(5)), Inclusive.at(8).to(Exclusive.at(9))]
+/// );
+/// assert_eq!(
+///     a.difference(&b).into_vec(),
+///     vec![Inclusive.at(0).to(Exclusive.at(2)), Inclusive.at(9).to(Exclusive.at(10))]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T, L = Inclusive, R = L>(Vec<Interval<T, L, R>>);
+
+impl<T, L, R> Default for IntervalSet<T, L, R> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T, L, R> core::ops::Deref for IntervalSet<T, L, R> {
+    type Target = [Interval<T, L, R>];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, L, R> From<IntervalSet<T, L, R>> for Vec<Interval<T, L, R>> {
+    fn from(set: IntervalSet<T, L, R>) -> Self {
+        set.0
+    }
+}
+
+impl<T, L, R> IntoIterator for IntervalSet<T, L, R> {
+    type Item = Interval<T, L, R>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, L, R> IntoIterator for &'a IntervalSet<T, L, R> {
+    type Item = &'a Interval<T, L, R>;
+    type IntoIter = core::slice::Iter<'a, Interval<T, L, R>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T, L, R> IntervalSet<T, L, R> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn into_vec(self) -> Vec<Interval<T, L, R>> {
+        self.into()
+    }
+}
+
+impl<T: PartialOrd + Clone, L: BoundaryOf<Left>, R: BoundaryOf<Right>> IntervalSet<T, L, R> {
+    /// Find the interval containing `t` by binary search in *O(log n)*.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive, IntervalSet};
+    /// let a: IntervalSet<_, _, _> = [
+    ///     Inclusive.at(0).to(Exclusive.at(3)),
+    ///     Inclusive.at(5).to(Exclusive.at(8)),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// assert_eq!(a.find_containing(&6), Some(&Inclusive.at(5).to(Exclusive.at(8))));
+    /// assert_eq!(a.find_containing(&3), None);
+    /// ```
+    pub fn find_containing(&self, t: &T) -> Option<&Interval<T, L, R>> {
+        Interval::find_containing(&self.0, t)
+    }
+
+    pub fn contains(&self, t: &T) -> bool {
+        self.find_containing(t).is_some()
+    }
+
+    pub fn measure(&self) -> T
+    where
+        T: num::Zero + core::ops::Sub<Output = T>,
+    {
+        self.0
+            .iter()
+            .fold(T::zero(), |acc, item| acc + item.measure())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut result = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.0.len() && j < other.0.len() {
+            let (a, b) = (&self.0[i], &other.0[j]);
+            result.extend(a.intersection(b));
+            if a.right < b.right {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self(result)
+    }
+}
+
+impl<T, L, R> IntervalSet<T, L, R>
+where
+    T: PartialOrd + Clone,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+    L::Flip: BoundaryOf<Right>,
+    R::Flip: BoundaryOf<Left>,
+{
+    /// Insert `item` merging the overlapping or touching intervals.
+    pub fn insert(&mut self, item: Interval<T, L, R>) {
+        let begin = self
+            .0
+            .partition_point(|x| x.left < item.left && x.gap(&item).is_some());
+        let mut merged = item;
+        let mut end = begin;
+        while let Some(x) = self.0.get(end) {
+            if merged.gap(x).is_some() {
+                break;
+            }
+            merged = merged.span(x);
+            end += 1;
+        }
+        self.0.splice(begin..end, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        result.extend(other.iter().cloned());
+        result
+    }
+}
+
+impl<T, L, R> IntervalSet<T, L, R>
+where
+    T: PartialOrd + Clone,
+    L: BoundaryOf<Left, Flip = R>,
+    R: BoundaryOf<Right, Flip = L>,
+{
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = Vec::new();
+        for item in &self.0 {
+            let begin = other
+                .0
+                .partition_point(|x| x.left < item.left && !x.overlaps(item));
+            let mut rest = Some(item.clone());
+            for x in &other.0[begin..] {
+                let Some(r) = rest.take() else { break };
+                if !r.overlaps(x) {
+                    rest = Some(r);
+                    break;
+                }
+                let diff = r.difference(x);
+                result.extend(diff.lower);
+                rest = diff.upper;
+            }
+            result.extend(rest);
+        }
+        Self(result)
+    }
+}
+
+impl<T, L, R> Extend<Interval<T, L, R>> for IntervalSet<T, L, R>
+where
+    T: PartialOrd + Clone,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+    L::Flip: BoundaryOf<Right>,
+    R::Flip: BoundaryOf<Left>,
+{
+    fn extend<I: IntoIterator<Item = Interval<T, L, R>>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert(item);
+        }
+    }
+}
+
+impl<T, L, R> FromIterator<Interval<T, L, R>> for IntervalSet<T, L, R>
+where
+    T: PartialOrd + Clone,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+    L::Flip: BoundaryOf<Right>,
+    R::Flip: BoundaryOf<Left>,
+{
+    fn from_iter<I: IntoIterator<Item = Interval<T, L, R>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
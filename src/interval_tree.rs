@@ -0,0 +1,239 @@
+use crate::bound_type::{Left, Right};
+use crate::interval::is_valid_interval;
+use crate::traits::BoundaryOf;
+use crate::{Inclusive, Interval, LeftBounded, RightBounded};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone)]
+struct Node<T, V, L, R> {
+    interval: Interval<T, L, R>,
+    value: V,
+    max: RightBounded<T, R>,
+    height: usize,
+    lower: Option<Box<Self>>,
+    upper: Option<Box<Self>>,
+}
+
+fn height<T, V, L, R>(node: &Option<Box<Node<T, V, L, R>>>) -> usize {
+    node.as_ref().map_or(0, |node| node.height)
+}
+
+impl<T, V, L, R> Node<T, V, L, R>
+where
+    T: PartialOrd + Clone,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+{
+    fn new(interval: Interval<T, L, R>, value: V) -> Box<Self> {
+        Box::new(Self {
+            max: interval.right.clone(),
+            interval,
+            value,
+            height: 1,
+            lower: None,
+            upper: None,
+        })
+    }
+
+    fn update(&mut self) {
+        self.height = 1 + height(&self.lower).max(height(&self.upper));
+        let mut max = &self.interval.right;
+        for child in [&self.lower, &self.upper].into_iter().flatten() {
+            max = max.union(&child.max);
+        }
+        self.max = max.clone();
+    }
+
+    fn rotate_right(mut self: Box<Self>) -> Box<Self> {
+        let mut lower = self.lower.take().unwrap();
+        self.lower = lower.upper.take();
+        self.update();
+        lower.upper = Some(self);
+        lower.update();
+        lower
+    }
+
+    fn rotate_left(mut self: Box<Self>) -> Box<Self> {
+        let mut upper = self.upper.take().unwrap();
+        self.upper = upper.lower.take();
+        self.update();
+        upper.lower = Some(self);
+        upper.update();
+        upper
+    }
+
+    fn rebalance(mut self: Box<Self>) -> Box<Self> {
+        self.update();
+        let (hl, hu) = (height(&self.lower), height(&self.upper));
+        if hl > hu + 1 {
+            let lower = self.lower.take().unwrap();
+            self.lower = Some(if height(&lower.upper) > height(&lower.lower) {
+                lower.rotate_left()
+            } else {
+                lower
+            });
+            self.rotate_right()
+        } else if hu > hl + 1 {
+            let upper = self.upper.take().unwrap();
+            self.upper = Some(if height(&upper.lower) > height(&upper.upper) {
+                upper.rotate_right()
+            } else {
+                upper
+            });
+            self.rotate_left()
+        } else {
+            self
+        }
+    }
+
+    fn insert(node: Option<Box<Self>>, interval: Interval<T, L, R>, value: V) -> Box<Self> {
+        let Some(mut node) = node else {
+            return Self::new(interval, value);
+        };
+        if interval.left < node.interval.left {
+            node.lower = Some(Self::insert(node.lower.take(), interval, value));
+        } else {
+            node.upper = Some(Self::insert(node.upper.take(), interval, value));
+        }
+        node.rebalance()
+    }
+
+    /// Visit the nodes whose intervals may overlap `[lower, upper]` where `lower` and `upper` are given as half bounds.
+    fn query<'a>(
+        &'a self,
+        lower: &LeftBounded<T, impl BoundaryOf<Left>>,
+        upper: &RightBounded<T, impl BoundaryOf<Right>>,
+        found: &mut Vec<(&'a Interval<T, L, R>, &'a V)>,
+    ) {
+        if !is_valid_interval(lower, &self.max) {
+            return;
+        }
+        if let Some(node) = &self.lower {
+            node.query(lower, upper, found);
+        }
+        if !is_valid_interval(&self.interval.left, upper) {
+            return;
+        }
+        if is_valid_interval(lower, &self.interval.right) {
+            found.push((&self.interval, &self.value));
+        }
+        if let Some(node) = &self.upper {
+            node.query(lower, upper, found);
+        }
+    }
+}
+
+/// Interval tree, i.e., a balanced binary search tree of intervals augmented with the maximum right bound of each subtree.
+/// Stabbing and overlap queries take *O(log n + k)* where *k* is the number of reported items.
+/// ```
+/// use inter_val::{Inclusive, Exclusive, IntervalTree};
+/// let tree: IntervalTree<i32, &str, Inclusive, Exclusive> = [
+///     (Inclusive.at(0).to(Exclusive.at(5)), "a"),
+///     (Inclusive.at(3).to(Exclusive.at(8)), "b"),
+///     (Inclusive.at(10).to(Exclusive.at(12)), "c"),
+/// ]
+/// .into_iter()
+/// .collect();
+/// assert_eq!(tree.len(), 3);
+///
+/// let mut found: Vec<_> = tree.query_point(&4).into_iter().map(|(_, v)| *v).collect();
+/// found.sort();
+/// assert_eq!(found, vec!["a", "b"]);
+/// assert!(tree.query_point(&8).is_empty());
+///
+/// let found: Vec<_> = tree
+///     .query_overlapping(&Inclusive.at(6).to(Exclusive.at(11)))
+///     .into_iter()
+///     .map(|(_, v)| *v)
+///     .collect();
+/// assert_eq!(found, vec!["b", "c"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntervalTree<T, V, L = Inclusive, R = L> {
+    root: Option<Box<Node<T, V, L, R>>>,
+    len: usize,
+}
+
+impl<T, V, L, R> Default for IntervalTree<T, V, L, R> {
+    fn default() -> Self {
+        Self { root: None, len: 0 }
+    }
+}
+
+impl<T, V, L, R> IntervalTree<T, V, L, R> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+}
+
+impl<T, V, L, R> IntervalTree<T, V, L, R>
+where
+    T: PartialOrd + Clone,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+{
+    pub fn insert(&mut self, interval: Interval<T, L, R>, value: V) {
+        self.root = Some(Node::insert(self.root.take(), interval, value));
+        self.len += 1;
+    }
+
+    /// Items whose intervals contain `t`, in ascending order of the left bounds.
+    pub fn query_point(&self, t: &T) -> Vec<(&Interval<T, L, R>, &V)> {
+        let lower: LeftBounded<T, Inclusive> = Inclusive.at(t.clone()).into();
+        let upper: RightBounded<T, Inclusive> = Inclusive.at(t.clone()).into();
+        let mut found = Vec::new();
+        if let Some(root) = &self.root {
+            root.query(&lower, &upper, &mut found);
+        }
+        found
+    }
+
+    /// Items whose intervals overlap `interval`, in ascending order of the left bounds.
+    pub fn query_overlapping<L2, R2>(
+        &self,
+        interval: &Interval<T, L2, R2>,
+    ) -> Vec<(&Interval<T, L, R>, &V)>
+    where
+        L2: BoundaryOf<Left>,
+        R2: BoundaryOf<Right>,
+    {
+        let mut found = Vec::new();
+        if let Some(root) = &self.root {
+            root.query(&interval.left, &interval.right, &mut found);
+        }
+        found
+    }
+}
+
+impl<T, V, L, R> Extend<(Interval<T, L, R>, V)> for IntervalTree<T, V, L, R>
+where
+    T: PartialOrd + Clone,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+{
+    fn extend<I: IntoIterator<Item = (Interval<T, L, R>, V)>>(&mut self, iter: I) {
+        for (interval, value) in iter {
+            self.insert(interval, value);
+        }
+    }
+}
+
+impl<T, V, L, R> FromIterator<(Interval<T, L, R>, V)> for IntervalTree<T, V, L, R>
+where
+    T: PartialOrd + Clone,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+{
+    fn from_iter<I: IntoIterator<Item = (Interval<T, L, R>, V)>>(iter: I) -> Self {
+        let mut tree = Self::new();
+        tree.extend(iter);
+        tree
+    }
+}
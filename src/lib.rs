@@ -39,11 +39,6 @@
 //! let hull = Interval::<_>::hull_many(vec![3, 9, 2, 5]).unwrap(); // [2, 9]
 //! assert_eq!(hull, Inclusive.between(2, 9));
 //!
-//! // Linear interpolation
-//! assert_eq!(b.lerp(0.0), *b.inf());
-//! assert_eq!(b.lerp(1.0), *b.sup());
-//! assert_eq!(b.lerp(0.2), 0.8 * *b.inf() + 0.2 * *b.sup());
-//!
 //! // Split
 //! let (lower, upper) = b.split_at(3.45); // Split [1.23, 4.56) at 3.45
 //! assert_eq!(lower.inf(), b.inf());
@@ -68,15 +63,39 @@
 //! let b = a.hull(&[12.3, 7.5]);
 //! assert_eq!(b, Box2::between(&[0.0, 5.0], &[12.3, 20.0]));
 //! ```
+//!
+//! # `no_std`
+//! The default `std` feature can be disabled to use the crate in `no_std` environments, where `alloc` is still required for the `Vec`-returning methods.
+//! Methods requiring `num::Float`, e.g., `lerp()` and `iou()`, need either the `std` or the `libm` feature.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "approx")]
+mod approx_eq;
+#[cfg(feature = "quickcheck")]
+mod arbitrary;
+mod arithmetic;
 mod bound;
 mod bound_type;
 mod converters;
 mod half;
+mod int_interval_set;
 mod interval;
+mod interval_batch;
 mod interval_box;
+mod interval_set;
+mod interval_tree;
 mod ndim;
 mod nullable;
+mod parse;
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "rand")]
+mod sampling;
 mod std_range;
+#[cfg(feature = "proptest")]
+mod strategy;
 mod tests;
 mod traits;
 
@@ -85,14 +104,23 @@ use traits::BoundaryOf;
 
 pub use bound::Bound;
 pub use bound_type::{BoundType, Exclusive, Inclusive};
+pub use converters::StaticInterval;
 pub use half::{HalfBounded, LeftBounded, RightBounded};
-pub use interval::{Interval, IntervalDifference, IntervalUnion};
+pub use int_interval_set::IntIntervalSet;
+pub use interval::{
+    BoundPolicy, IntegerIter, Interval, IntervalDifference, IntervalRelationSummary, IntervalUnion,
+};
+pub use interval_batch::IntervalBatch;
 pub use interval_box::BoxN;
+pub use interval_set::IntervalSet;
+pub use interval_tree::IntervalTree;
 pub use ndim::NDim;
 pub use nullable::Nullable;
+#[cfg(feature = "rand")]
+pub use sampling::UniformInterval;
 
 impl Inclusive {
-    pub fn at<T>(self, t: T) -> Bound<T, Self> {
+    pub const fn at<T>(self, t: T) -> Bound<T, Self> {
         Bound {
             limit: t,
             bound_type: self,
@@ -108,7 +136,7 @@ impl Inclusive {
     }
 }
 impl Exclusive {
-    pub fn at<T>(self, t: T) -> Bound<T, Self> {
+    pub const fn at<T>(self, t: T) -> Bound<T, Self> {
         Bound {
             limit: t,
             bound_type: self,
@@ -127,7 +155,7 @@ impl Exclusive {
     }
 }
 impl BoundType {
-    pub fn at<T>(self, t: T) -> Bound<T, Self> {
+    pub const fn at<T>(self, t: T) -> Bound<T, Self> {
         Bound {
             limit: t,
             bound_type: self,
@@ -149,12 +177,67 @@ impl<T: PartialOrd, B: BoundaryOf<Left>> Bound<T, B> {
     pub fn try_to<R: BoundaryOf<Right>>(self, r: Bound<T, R>) -> Option<Interval<T, B, R>> {
         Interval::try_new(self, r)
     }
+
+    /// Ray extending to the right, i.e., *[a, ∞)* or *(a, ∞)*.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let ray = Inclusive.at(5).ray_right();  // [5, ∞)
+    /// assert!(ray.contains(&5));
+    /// assert!(ray.contains(&i32::MAX));
+    /// assert!(!Exclusive.at(5).ray_right().contains(&5));
+    /// assert_eq!(ray.intersect_interval(&Inclusive.between(0, 10)), Some(Inclusive.between(5, 10)));
+    /// assert_eq!(ray.intersect_interval(&Inclusive.between(0, 3)), None);
+    /// assert_eq!(ray.union(&Inclusive.at(3).ray_right()), &Inclusive.at(3).ray_right());
+    /// ```
+    pub fn ray_right(self) -> LeftBounded<T, B> {
+        self.into()
+    }
+}
+
+impl<T: PartialOrd, B: BoundaryOf<Right>> Bound<T, B> {
+    /// Ray extending to the left, i.e., *(-∞, b]* or *(-∞, b)*.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let ray = Exclusive.at(5).ray_left();  // (-∞, 5)
+    /// assert!(ray.contains(&4));
+    /// assert!(!ray.contains(&5));
+    /// assert_eq!(
+    ///     ray.intersect_interval(&Inclusive.at(0).to(Exclusive.at(10))),
+    ///     Some(Inclusive.at(0).to(Exclusive.at(5)))
+    /// );
+    /// assert_eq!(ray.intersection(&Exclusive.at(3).ray_left()), &Exclusive.at(3).ray_left());
+    /// ```
+    pub fn ray_left(self) -> RightBounded<T, B> {
+        self.into()
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
 #[error("left boundary must be less than or equal to right boundary")]
 pub struct IntervalIsEmpty;
 
+/// Error of parsing an interval literal like `"[a, b)"`.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseIntervalError<E> {
+    #[error("interval must start with '[' or '(' and end with ']' or ')'")]
+    MalformedBracket,
+    #[error("missing comma between endpoints")]
+    MissingComma,
+    #[error("failed to parse endpoint: {0}")]
+    Endpoint(E),
+    #[error("left boundary must be less than or equal to right boundary")]
+    Empty,
+}
+
+/// Error of `Interval::validate_monotonic_within()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ValidationError {
+    #[error("value at index {index} is out of the interval")]
+    OutOfInterval { index: usize },
+    #[error("value at index {index} is less than the previous value")]
+    NotMonotonic { index: usize },
+}
+
 pub type OpenInterval<T> = Interval<T, Exclusive>;
 pub type GeneralInterval<T> = Interval<T, BoundType>;
 pub type Box2<T, L = Inclusive, R = L> = BoxN<2, T, L, R>;
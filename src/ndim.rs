@@ -31,37 +31,37 @@ pub struct Xyzw<T> {
     pub w: T,
 }
 
-impl<T> std::ops::Deref for NDim<2, T> {
+impl<T> core::ops::Deref for NDim<2, T> {
     type Target = Xy<T>;
     fn deref(&self) -> &Self::Target {
-        unsafe { std::mem::transmute(self) }
+        unsafe { core::mem::transmute(self) }
     }
 }
-impl<T> std::ops::DerefMut for NDim<2, T> {
+impl<T> core::ops::DerefMut for NDim<2, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { std::mem::transmute(self) }
+        unsafe { core::mem::transmute(self) }
     }
 }
-impl<T> std::ops::Deref for NDim<3, T> {
+impl<T> core::ops::Deref for NDim<3, T> {
     type Target = Xyz<T>;
     fn deref(&self) -> &Self::Target {
-        unsafe { std::mem::transmute(self) }
+        unsafe { core::mem::transmute(self) }
     }
 }
-impl<T> std::ops::DerefMut for NDim<3, T> {
+impl<T> core::ops::DerefMut for NDim<3, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { std::mem::transmute(self) }
+        unsafe { core::mem::transmute(self) }
     }
 }
-impl<T> std::ops::Deref for NDim<4, T> {
+impl<T> core::ops::Deref for NDim<4, T> {
     type Target = Xyzw<T>;
     fn deref(&self) -> &Self::Target {
-        unsafe { std::mem::transmute(self) }
+        unsafe { core::mem::transmute(self) }
     }
 }
-impl<T> std::ops::DerefMut for NDim<4, T> {
+impl<T> core::ops::DerefMut for NDim<4, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { std::mem::transmute(self) }
+        unsafe { core::mem::transmute(self) }
     }
 }
 
@@ -75,7 +75,7 @@ impl<const N: usize, T> NDim<N, T> {
     pub fn into_array(self) -> [T; N] {
         self.0
     }
-    pub fn iter(&self) -> std::slice::Iter<T> {
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
         self.0.iter()
     }
 }
@@ -94,13 +94,13 @@ impl<T> NDim<4, T> {
         Self([x, y, z, w])
     }
 }
-impl<const N: usize, T> std::ops::Index<usize> for NDim<N, T> {
+impl<const N: usize, T> core::ops::Index<usize> for NDim<N, T> {
     type Output = T;
     fn index(&self, index: usize) -> &Self::Output {
         &self.0[index]
     }
 }
-impl<const N: usize, T> std::ops::IndexMut<usize> for NDim<N, T> {
+impl<const N: usize, T> core::ops::IndexMut<usize> for NDim<N, T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
     }
@@ -117,14 +117,14 @@ impl<const N: usize, T> From<NDim<N, T>> for [T; N] {
 }
 impl<const N: usize, T> IntoIterator for NDim<N, T> {
     type Item = T;
-    type IntoIter = std::array::IntoIter<T, N>;
+    type IntoIter = core::array::IntoIter<T, N>;
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
     }
 }
 impl<'a, const N: usize, T> IntoIterator for &'a NDim<N, T> {
     type Item = &'a T;
-    type IntoIter = std::slice::Iter<'a, T>;
+    type IntoIter = core::slice::Iter<'a, T>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
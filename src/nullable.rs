@@ -4,6 +4,73 @@ use crate::{
     Bound, Interval,
 };
 
+/// Null-aware set operations, where null is regarded as the empty set.
+/// ```
+/// use inter_val::{Nullable, Interval, Inclusive, Exclusive};
+/// let a = Inclusive.at(0).to(Exclusive.at(5));  // [0, 5)
+/// let b = Inclusive.at(3).to(Exclusive.at(8));  // [3, 8)
+/// let c = Inclusive.at(6).to(Exclusive.at(9));  // [6, 9)
+/// let ab = a.as_nullable().intersection(&b);    // [3, 5)
+/// assert_eq!(ab.unwrap(), Inclusive.at(3).to(Exclusive.at(5)));
+///
+/// // The middle result is empty, and so is the rest of the chain.
+/// let ac = a.as_nullable().intersection(&c);
+/// assert!(ac.is_null());
+/// let acb = ac.intersection(&b);
+/// assert!(acb.is_null());
+/// assert!(!acb.contains(&4));
+/// assert_eq!(acb.measure(), 0);
+/// assert_eq!(acb.span(&b).unwrap(), b);
+/// ```
+impl<T, L, R> Nullable<Interval<T, L, R>>
+where
+    T: PartialOrd + Clone,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+{
+    pub fn intersection(&self, other: &Interval<T, L, R>) -> Self {
+        self.as_ref().and_then(|i| i.intersection(other)).into()
+    }
+    pub fn span(&self, other: &Interval<T, L, R>) -> Self {
+        self.as_ref()
+            .map_or_else(|| other.clone(), |i| i.span(other))
+            .into()
+    }
+    pub fn contains(&self, t: &T) -> bool {
+        self.as_ref().is_some_and(|i| i.contains(t))
+    }
+    pub fn measure(&self) -> T
+    where
+        T: num::Zero + core::ops::Sub<Output = T>,
+    {
+        self.as_ref().map_or_else(T::zero, |i| i.measure())
+    }
+
+    /// Intersection of all `items`. Null if `items` is empty or any of them are disjoint.
+    /// ```
+    /// use inter_val::{Nullable, Interval};
+    /// let a: Interval<i32> = Interval::between(0, 10);
+    /// let b = Interval::between(5, 15);
+    /// let c = Interval::between(8, 12);
+    /// assert_eq!(Nullable::intersect_all([a, b, c]).unwrap(), Interval::between(8, 10));
+    /// assert!(Nullable::intersect_all([a, b, Interval::between(11, 20)]).is_null());
+    /// assert!(Nullable::intersect_all(Vec::<Interval<i32>>::new()).is_null());
+    /// ```
+    pub fn intersect_all<A: core::borrow::Borrow<Interval<T, L, R>>>(
+        items: impl IntoIterator<Item = A>,
+    ) -> Self {
+        let mut items = items.into_iter();
+        let Some(first) = items.next() else {
+            return Self::NULL;
+        };
+        items
+            .try_fold(first.borrow().clone(), |acc, item| {
+                acc.intersection(item.borrow())
+            })
+            .into()
+    }
+}
+
 /// Wrapper of `Option<T>` to implement `Sum` trait.
 /// ```
 /// use inter_val::{Nullable, Interval, Inclusive, Exclusive};
@@ -20,13 +87,13 @@ use crate::{
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct Nullable<T>(pub Option<T>);
 
-impl<T> std::ops::Deref for Nullable<T> {
+impl<T> core::ops::Deref for Nullable<T> {
     type Target = Option<T>;
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
-impl<T> std::ops::DerefMut for Nullable<T> {
+impl<T> core::ops::DerefMut for Nullable<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
@@ -74,7 +141,7 @@ impl<T> Nullable<T> {
 /// assert_eq!(span.as_ref().unwrap().left().limit, 0);
 /// assert_eq!(span.as_ref().unwrap().right().limit, 10);
 /// ```
-impl<T, L, R> std::iter::Sum<Interval<T, L, R>> for Nullable<Interval<T, L, R>>
+impl<T, L, R> core::iter::Sum<Interval<T, L, R>> for Nullable<Interval<T, L, R>>
 where
     T: PartialOrd + Clone,
     L: BoundaryOf<Left>,
@@ -85,12 +152,34 @@ where
     }
 }
 
+/// Dual of the span `Sum`: folds intervals via `intersection`.
+/// ```
+/// use inter_val::{Nullable, Interval, Inclusive, Exclusive};
+/// let a = Inclusive.at(0).to(Exclusive.at(5));  // [0, 5)
+/// let b = Inclusive.at(3).to(Exclusive.at(8));  // [3, 8)
+/// let c = Inclusive.at(6).to(Exclusive.at(9));  // [6, 9)
+/// let ab: Nullable<Interval<_, _, _>> = vec![a, b].into_iter().product(); // [3, 5)
+/// assert_eq!(ab.unwrap(), Inclusive.at(3).to(Exclusive.at(5)));
+/// let abc: Nullable<Interval<_, _, _>> = vec![a, b, c].into_iter().product();
+/// assert!(abc.is_null());
+/// ```
+impl<T, L, R> core::iter::Product<Interval<T, L, R>> for Nullable<Interval<T, L, R>>
+where
+    T: PartialOrd + Clone,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+{
+    fn product<I: Iterator<Item = Interval<T, L, R>>>(iter: I) -> Self {
+        Self::intersect_all(iter)
+    }
+}
+
 /// ```
 /// use inter_val::{Interval, Nullable};
 /// let a: Nullable<Interval<i32>> = vec![1, 6, 2, 8, 3].into_iter().sum();
 /// assert_eq!(a.unwrap(), Interval::between(1, 8));
 /// ```
-impl<T, L, R> std::iter::Sum<T> for Nullable<Interval<T, L, R>>
+impl<T, L, R> core::iter::Sum<T> for Nullable<Interval<T, L, R>>
 where
     T: PartialOrd + Clone + Into<Bound<T, L>> + Into<Bound<T, R>>,
     L: BoundaryOf<Left>,
@@ -106,7 +195,7 @@ where
 /// let a = vec![1, 6, 2, 8, 3].into_iter().collect::<Nullable<Interval<i32>>>();
 /// assert_eq!(a.unwrap(), Interval::between(1, 8));
 /// ```
-impl<T, L, R> std::iter::FromIterator<T> for Nullable<Interval<T, L, R>>
+impl<T, L, R> core::iter::FromIterator<T> for Nullable<Interval<T, L, R>>
 where
     T: PartialOrd + Clone + Into<Bound<T, L>> + Into<Bound<T, R>>,
     L: BoundaryOf<Left>,
@@ -0,0 +1,39 @@
+use crate::{BoundType, Interval, ParseIntervalError};
+
+/// Parse interval literals like `"[a, b]"`, `"(a, b)"`, `"[a, b)"`, and `"(a, b]"`.
+/// ```
+/// use inter_val::{BoundType, Interval, ParseIntervalError};
+/// let a: Interval<f64, BoundType> = "(1.5, 4.5]".parse().unwrap();
+/// assert_eq!(a, BoundType::Exclusive.at(1.5).to(BoundType::Inclusive.at(4.5)));
+///
+/// let a: Interval<i32, BoundType> = " [ -3 ,5 ) ".parse().unwrap();
+/// assert_eq!(a, BoundType::Inclusive.at(-3).to(BoundType::Exclusive.at(5)));
+///
+/// type Error = ParseIntervalError<std::num::ParseIntError>;
+/// assert_eq!("{0, 1]".parse::<Interval<i32, BoundType>>(), Err(Error::MalformedBracket));
+/// assert_eq!("[0; 1]".parse::<Interval<i32, BoundType>>(), Err(Error::MissingComma));
+/// assert!(matches!("[0, x]".parse::<Interval<i32, BoundType>>(), Err(Error::Endpoint(_))));
+/// assert_eq!("[1, 1)".parse::<Interval<i32, BoundType>>(), Err(Error::Empty));
+/// ```
+impl<T: PartialOrd + core::str::FromStr> core::str::FromStr for Interval<T, BoundType> {
+    type Err = ParseIntervalError<T::Err>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let left = match s.chars().next() {
+            Some('[') => BoundType::Inclusive,
+            Some('(') => BoundType::Exclusive,
+            _ => return Err(ParseIntervalError::MalformedBracket),
+        };
+        let right = match s.chars().last() {
+            Some(']') if s.len() > 1 => BoundType::Inclusive,
+            Some(')') if s.len() > 1 => BoundType::Exclusive,
+            _ => return Err(ParseIntervalError::MalformedBracket),
+        };
+        let (a, b) = s[1..s.len() - 1]
+            .split_once(',')
+            .ok_or(ParseIntervalError::MissingComma)?;
+        let a = a.trim().parse().map_err(ParseIntervalError::Endpoint)?;
+        let b = b.trim().parse().map_err(ParseIntervalError::Endpoint)?;
+        Self::try_new(left.at(a), right.at(b)).ok_or(ParseIntervalError::Empty)
+    }
+}
@@ -0,0 +1,53 @@
+use crate::bound_type::{Left, Right};
+use crate::traits::BoundaryOf;
+use crate::{Exclusive, Inclusive, Interval};
+
+macro_rules! impl_pod {
+    ($l:ty, $r:ty) => {
+        // SAFETY: `Interval`, `HalfBounded` and `Bound` are `#[repr(C)]` and the static bound types are ZSTs,
+        // so `Interval<T, $l, $r>` is laid out as exactly two `T`s without padding.
+        unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Interval<T, $l, $r> {}
+        unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Interval<T, $l, $r> {}
+    };
+}
+impl_pod!(Inclusive, Inclusive);
+impl_pod!(Inclusive, Exclusive);
+impl_pod!(Exclusive, Inclusive);
+impl_pod!(Exclusive, Exclusive);
+
+impl<T, L, R> Interval<T, L, R>
+where
+    T: bytemuck::Pod + PartialOrd,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+    Self: bytemuck::Pod,
+{
+    /// View the interval as `2 * size_of::<T>()` bytes, i.e., `inf` followed by `sup`.
+    /// The bound types are not encoded since they are statically determined.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(1).to(Exclusive.at(3));    // [1, 3)
+    /// assert_eq!(a.as_bytes().len(), 2 * std::mem::size_of::<i32>());
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::bytes_of(self)
+    }
+
+    /// Decode an interval encoded by `as_bytes()`.
+    /// Return `None` if the length of `bytes` mismatches or the decoded interval is empty.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive};
+    /// let a = Inclusive.at(1).to(Exclusive.at(3));    // [1, 3)
+    /// let b = Interval::<i32, Inclusive, Exclusive>::from_bytes(a.as_bytes()).unwrap();
+    /// assert_eq!(a, b);
+    ///
+    /// assert!(Interval::<i32, Inclusive, Exclusive>::from_bytes(&a.as_bytes()[1..]).is_none());
+    ///
+    /// let bytes: Vec<u8> = [3i32, 3i32].iter().flat_map(|x| x.to_ne_bytes()).collect();
+    /// assert!(Interval::<i32, Inclusive, Exclusive>::from_bytes(&bytes).is_none()); // [3, 3) is empty.
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let decoded: Self = bytemuck::try_pod_read_unaligned(bytes).ok()?;
+        Self::new_(decoded.left, decoded.right)
+    }
+}
@@ -0,0 +1,106 @@
+use crate::bound_type::{Left, Right};
+use crate::traits::BoundaryOf;
+use crate::Interval;
+use rand::distributions::uniform::SampleUniform;
+use rand::distributions::Distribution;
+use rand::Rng;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T, L, R> Interval<T, L, R>
+where
+    T: num::Float + SampleUniform,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+{
+    /// Draw a point uniformly from the interval. Exclusive bounds are never returned.
+    /// An open interval containing no representable float, e.g., *(0, ε)* with `ε` = the smallest positive float, never returns.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Exclusive.at(0.0).to(Inclusive.at(1.0));   // (0, 1]
+    /// let mut rng = rand::thread_rng();
+    /// for _ in 0..1000 {
+    ///     assert!(a.contains(&a.sample(&mut rng)));
+    /// }
+    /// ```
+    pub fn sample<G: Rng + ?Sized>(&self, rng: &mut G) -> T {
+        loop {
+            let t = rng.gen_range(*self.inf()..=*self.sup());
+            if self.contains(&t) {
+                return t;
+            }
+        }
+    }
+}
+
+impl<T, L, R> Interval<T, L, R>
+where
+    T: num::PrimInt + SampleUniform,
+    L: BoundaryOf<Left>,
+    R: BoundaryOf<Right>,
+{
+    /// Draw an integer uniformly from the integers contained in the interval.
+    /// Return `None` if the interval contains no integer, e.g., *(3, 4)*.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// let a = Exclusive.at(0).to(Inclusive.at(3));   // (0, 3]
+    /// let mut rng = rand::thread_rng();
+    /// for _ in 0..1000 {
+    ///     let t = a.sample_integer(&mut rng).unwrap();
+    ///     assert!((1..=3).contains(&t));
+    /// }
+    /// assert!(Exclusive.between(3, 4).sample_integer(&mut rng).is_none());
+    /// ```
+    pub fn sample_integer<G: Rng + ?Sized>(&self, rng: &mut G) -> Option<T> {
+        let (first, last) = self.integer_bounds();
+        (first <= last).then(|| rng.gen_range(first..=last))
+    }
+}
+
+impl<T, L, R> Interval<T, L, R> {
+    /// Uniform distribution over the interval, which composes with `Rng::sample_iter()`.
+    /// ```
+    /// use inter_val::{Inclusive, Exclusive};
+    /// use rand::Rng;
+    /// let a = Inclusive.at(-1.0).to(Exclusive.at(1.0));  // [-1, 1)
+    /// let samples: Vec<f64> = rand::thread_rng().sample_iter(a.uniform()).take(100).collect();
+    /// assert!(samples.iter().all(|t| a.contains(t)));
+    ///
+    /// let b = Inclusive.at(0).to(Exclusive.at(10));    // [0, 10)
+    /// let samples: Vec<i32> = rand::thread_rng().sample_iter(b.uniform()).take(100).collect();
+    /// assert!(samples.iter().all(|t| b.contains(t)));
+    /// ```
+    pub fn uniform(self) -> UniformInterval<T, L, R> {
+        UniformInterval(self)
+    }
+}
+
+/// Uniform distribution over an interval. See `Interval::uniform()`.
+/// Sampling integers panics if the interval contains no integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UniformInterval<T, L, R>(Interval<T, L, R>);
+
+#[cfg(any(feature = "std", feature = "libm"))]
+macro_rules! impl_float_distribution {
+    ($($t:ty),*) => {$(
+        impl<L: BoundaryOf<Left>, R: BoundaryOf<Right>> Distribution<$t> for UniformInterval<$t, L, R> {
+            fn sample<G: Rng + ?Sized>(&self, rng: &mut G) -> $t {
+                self.0.sample(rng)
+            }
+        }
+    )*};
+}
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_float_distribution!(f32, f64);
+
+macro_rules! impl_integer_distribution {
+    ($($t:ty),*) => {$(
+        impl<L: BoundaryOf<Left>, R: BoundaryOf<Right>> Distribution<$t> for UniformInterval<$t, L, R> {
+            fn sample<G: Rng + ?Sized>(&self, rng: &mut G) -> $t {
+                self.0
+                    .sample_integer(rng)
+                    .expect("interval contains no integer")
+            }
+        }
+    )*};
+}
+impl_integer_distribution!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
@@ -1,6 +1,6 @@
 mod impl_range_bounds {
     use crate::{Exclusive, Inclusive, LeftBounded, RightBounded};
-    use std::ops::{Bound, RangeBounds};
+    use core::ops::{Bound, RangeBounds};
 
     impl<T: PartialOrd> RangeBounds<T> for LeftBounded<T, Inclusive> {
         fn start_bound(&self) -> Bound<&T> {
@@ -46,10 +46,15 @@ mod converters {
     /// assert_eq!(a.type_id(), TypeId::of::<Interval<i32, Inclusive, Exclusive>>());
     /// assert_eq!(a.left().limit, 2);
     /// assert_eq!(a.right().limit, 4);
+    ///
+    /// // Float ranges are converted as well.
+    /// let b: Interval<_, _, _> = (2.74..4.26).try_into().unwrap();
+    /// assert_eq!(b, Inclusive.at(2.74).to(Exclusive.at(4.26)));
+    /// assert!(Interval::<f64, Inclusive, Exclusive>::try_from(4.26..2.74).is_err());
     /// ```
-    impl<T: PartialOrd> TryFrom<std::ops::Range<T>> for Interval<T, Inclusive, Exclusive> {
+    impl<T: PartialOrd> TryFrom<core::ops::Range<T>> for Interval<T, Inclusive, Exclusive> {
         type Error = IntervalIsEmpty;
-        fn try_from(r: std::ops::Range<T>) -> Result<Self, Self::Error> {
+        fn try_from(r: core::ops::Range<T>) -> Result<Self, Self::Error> {
             Self::try_new(r.start.into(), r.end.into()).ok_or(IntervalIsEmpty)
         }
     }
@@ -61,10 +66,14 @@ mod converters {
     /// assert_eq!(a.type_id(), TypeId::of::<Interval<i32, Inclusive, Inclusive>>());
     /// assert_eq!(a.left().limit, 2);
     /// assert_eq!(a.right().limit, 4);
+    ///
+    /// let b: Interval<_, _, _> = (2.74..=4.26).try_into().unwrap();
+    /// assert_eq!(b, Inclusive.between(2.74, 4.26));
+    /// assert!(Interval::<f64>::try_from(4.26..=2.74).is_err());
     /// ```
-    impl<T: PartialOrd> TryFrom<std::ops::RangeInclusive<T>> for Interval<T, Inclusive> {
+    impl<T: PartialOrd> TryFrom<core::ops::RangeInclusive<T>> for Interval<T, Inclusive> {
         type Error = IntervalIsEmpty;
-        fn try_from(r: std::ops::RangeInclusive<T>) -> Result<Self, Self::Error> {
+        fn try_from(r: core::ops::RangeInclusive<T>) -> Result<Self, Self::Error> {
             let (left, right) = r.into_inner();
             Self::try_new(left.into(), right.into()).ok_or(IntervalIsEmpty)
         }
@@ -77,7 +86,7 @@ mod converters {
     /// assert_eq!(dst.start, 0);
     /// assert_eq!(dst.end, 10);
     /// ```
-    impl<T> From<Interval<T, Inclusive, Exclusive>> for std::ops::Range<T> {
+    impl<T> From<Interval<T, Inclusive, Exclusive>> for core::ops::Range<T> {
         fn from(i: Interval<T, Inclusive, Exclusive>) -> Self {
             i.left.0.limit..i.right.0.limit
         }
@@ -90,9 +99,52 @@ mod converters {
     /// assert_eq!(dst.start(), &0);
     /// assert_eq!(dst.end(), &10);
     /// ```
-    impl<T> From<Interval<T, Inclusive, Inclusive>> for std::ops::RangeInclusive<T> {
+    impl<T> From<Interval<T, Inclusive, Inclusive>> for core::ops::RangeInclusive<T> {
         fn from(i: Interval<T, Inclusive, Inclusive>) -> Self {
             i.left.0.limit..=i.right.0.limit
         }
     }
 }
+
+mod from_range_bounds {
+    use crate::{BoundType, Interval};
+    use core::ops::{Bound, RangeBounds};
+
+    impl<T: PartialOrd + Clone + num::Bounded> Interval<T, BoundType> {
+        /// Convert any `RangeBounds` like `a..b`, `a..=b`, `a..`, `..b`, and `..=b`.
+        /// An unbounded side is closed at `T::min_value()` or `T::max_value()` like `Interval::at_least()` and `Interval::at_most()`.
+        /// Return `None` if the range is empty or unbounded on both sides, i.e., `..`, for which use `Interval::whole()` explicitly.
+        /// This stands in for `TryFrom<Range<T>>` and `TryFrom<RangeInclusive<T>>`, which would make `(a..b).try_into()` ambiguous for `Interval<_, _, _>`.
+        /// ```
+        /// use inter_val::{BoundType, GeneralInterval};
+        /// let a = GeneralInterval::from_range_bounds(2..5).unwrap();
+        /// assert_eq!(a, BoundType::Inclusive.at(2).to(BoundType::Exclusive.at(5)));
+        ///
+        /// let a = GeneralInterval::from_range_bounds(100i8..).unwrap();
+        /// assert_eq!(a, BoundType::Inclusive.at(100).to(BoundType::Inclusive.at(127)));
+        ///
+        /// let a = GeneralInterval::from_range_bounds(..-100i8).unwrap();
+        /// assert_eq!(a, BoundType::Inclusive.at(-128).to(BoundType::Exclusive.at(-100)));
+        ///
+        /// assert!(GeneralInterval::from_range_bounds(5..5).is_none());
+        /// assert!(GeneralInterval::<i32>::from_range_bounds(..).is_none());
+        /// ```
+        pub fn from_range_bounds(r: impl RangeBounds<T>) -> Option<Self> {
+            let (start, end) = (r.start_bound(), r.end_bound());
+            if let (Bound::Unbounded, Bound::Unbounded) = (start, end) {
+                return None;
+            }
+            let left = match start {
+                Bound::Included(t) => BoundType::Inclusive.at(t.clone()),
+                Bound::Excluded(t) => BoundType::Exclusive.at(t.clone()),
+                Bound::Unbounded => BoundType::Inclusive.at(T::min_value()),
+            };
+            let right = match end {
+                Bound::Included(t) => BoundType::Inclusive.at(t.clone()),
+                Bound::Excluded(t) => BoundType::Exclusive.at(t.clone()),
+                Bound::Unbounded => BoundType::Inclusive.at(T::max_value()),
+            };
+            Self::try_new(left, right)
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::bound_type::{Left, Right};
+use crate::traits::BoundaryOf;
+use crate::{Bound, BoundType, BoxN, Exclusive, Inclusive, Interval};
+use core::ops::RangeInclusive;
+use proptest::prelude::*;
+
+impl Arbitrary for Inclusive {
+    type Parameters = ();
+    type Strategy = Just<Self>;
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Inclusive)
+    }
+}
+
+impl Arbitrary for Exclusive {
+    type Parameters = ();
+    type Strategy = Just<Self>;
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Exclusive)
+    }
+}
+
+impl Arbitrary for BoundType {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![Just(BoundType::Inclusive), Just(BoundType::Exclusive)].boxed()
+    }
+}
+
+impl<T, L, R> Interval<T, L, R>
+where
+    T: PartialOrd + Clone + core::fmt::Debug,
+    L: BoundaryOf<Left> + Arbitrary,
+    R: BoundaryOf<Right> + Arbitrary,
+    RangeInclusive<T>: Strategy<Value = T>,
+{
+    /// Strategy generating non-empty intervals within `range`.
+    /// The bound types are drawn by `Arbitrary`, i.e., fixed for `Inclusive` and `Exclusive`, and random for `BoundType`.
+    /// Empty candidates like *[a, a)* are rejected, so `range` must not be a single point unless both bounds are inclusive.
+    /// ```
+    /// use inter_val::{Interval, Inclusive, Exclusive, GeneralInterval};
+    /// use proptest::prelude::*;
+    ///
+    /// proptest!(|(a in Interval::<i32, Inclusive, Exclusive>::arbitrary_in(-10..=10))| {
+    ///     prop_assert!(a.inf() < a.sup());
+    ///     prop_assert!(-10 <= *a.inf() && *a.sup() <= 10);
+    /// });
+    /// proptest!(|(a in GeneralInterval::<f64>::arbitrary_in(0.0..=1.0))| {
+    ///     prop_assert!(a.inf() <= a.sup());
+    ///     prop_assert!(a.lerp(0.5) >= 0.0);
+    /// });
+    /// ```
+    pub fn arbitrary_in(range: RangeInclusive<T>) -> impl Strategy<Value = Self> {
+        (range.clone(), range, any::<L>(), any::<R>()).prop_filter_map(
+            "empty interval",
+            |(a, b, l, r)| {
+                let (a, b) = if a <= b { (a, b) } else { (b, a) };
+                Interval::try_new(
+                    Bound {
+                        limit: a,
+                        bound_type: l,
+                    },
+                    Bound {
+                        limit: b,
+                        bound_type: r,
+                    },
+                )
+            },
+        )
+    }
+}
+
+impl<const N: usize, T, L, R> BoxN<N, T, L, R>
+where
+    T: PartialOrd + Clone + core::fmt::Debug,
+    L: BoundaryOf<Left> + Arbitrary + core::fmt::Debug,
+    R: BoundaryOf<Right> + Arbitrary + core::fmt::Debug,
+    RangeInclusive<T>: Strategy<Value = T>,
+{
+    /// Strategy generating boxes each of whose axes is generated by `Interval::arbitrary_in(range)`.
+    /// ```
+    /// use inter_val::Box2;
+    /// use proptest::prelude::*;
+    ///
+    /// proptest!(|(a in Box2::<f64>::arbitrary_in(-1.0..=1.0))| {
+    ///     prop_assert!(a.measure() >= 0.0);
+    ///     prop_assert!(a.contains(&a.center()));
+    /// });
+    /// ```
+    pub fn arbitrary_in(range: RangeInclusive<T>) -> impl Strategy<Value = Self> {
+        proptest::array::uniform::<_, N>(Interval::arbitrary_in(range)).prop_map(Self::from)
+    }
+}
@@ -1,4 +1,7 @@
 #![cfg(test)]
+extern crate std;
+
+use alloc::{vec, vec::Vec};
 use std::any::{Any, TypeId};
 
 use super::*;
@@ -92,4 +95,1080 @@ fn ordering() {
     let a: RightBounded<_, _> = BoundType::Inclusive.at(0).into();
     let b: RightBounded<_, _> = BoundType::Exclusive.at(0).into();
     assert!(a > b);
+
+    let a = BoundType::Inclusive.at(0).to(BoundType::Exclusive.at(3)); // [0, 3)
+    let b = BoundType::Inclusive.at(0).to(BoundType::Inclusive.at(3)); // [0, 3]
+    let c = BoundType::Inclusive.at(1).to(BoundType::Exclusive.at(2)); // [1, 2)
+    let d = BoundType::Exclusive.at(0).to(BoundType::Exclusive.at(1)); // (0, 1)
+    let lt = |x: &GeneralInterval<i32>, y| x.cmp_by_bounds(y) == std::cmp::Ordering::Less;
+    assert!(lt(&a, &b) && lt(&b, &c) && lt(&a, &c));
+    assert!(lt(&b, &d) && lt(&d, &c));
+    let mut items = vec![c, d, b, a];
+    items.sort_by(Interval::cmp_by_bounds);
+    assert_eq!(items, vec![a, b, d, c]);
+}
+
+#[test]
+fn interval_tree() {
+    let items: Vec<Interval<i32, Inclusive, Exclusive>> = (0..200)
+        .map(|i| {
+            let inf = (i * 37) % 101;
+            Inclusive.at(inf).to(Exclusive.at(inf + 1 + (i * 13) % 17))
+        })
+        .collect();
+    let tree: IntervalTree<_, _, _, _> = items.iter().cloned().zip(0..).collect();
+    assert_eq!(tree.len(), items.len());
+
+    for t in -5..125 {
+        let mut found: Vec<usize> = tree.query_point(&t).into_iter().map(|(_, i)| *i).collect();
+        found.sort();
+        let expected: Vec<usize> = (0..items.len())
+            .filter(|&i| items[i].contains(&t))
+            .collect();
+        assert_eq!(found, expected);
+    }
+
+    for q in [(0, 1), (10, 20), (50, 51), (100, 130), (-10, 0)] {
+        let q = Exclusive.at(q.0).to(Inclusive.at(q.1)).into();
+        let mut found: Vec<usize> = tree
+            .query_overlapping::<BoundType, BoundType>(&q)
+            .into_iter()
+            .map(|(_, i)| *i)
+            .collect();
+        found.sort();
+        let expected: Vec<usize> = (0..items.len())
+            .filter(|&i| GeneralInterval::from(items[i]).overlaps(&q))
+            .collect();
+        assert_eq!(found, expected);
+    }
+}
+
+#[test]
+fn interval_set() {
+    let make = |seed: i32, n: i32| -> IntervalSet<i32, Inclusive, Exclusive> {
+        (0..n)
+            .map(|i| {
+                let inf = (i * seed) % 61;
+                Inclusive.at(inf).to(Exclusive.at(inf + 1 + (i * 7) % 5))
+            })
+            .collect()
+    };
+    let a = make(37, 20);
+    let b = make(23, 15);
+    for set in [&a, &b, &a.union(&b), &a.intersection(&b), &a.difference(&b)] {
+        for w in set.windows(2) {
+            assert!(w[0].gap(&w[1]).is_some());
+            assert!(w[0].left < w[1].left);
+        }
+    }
+    for t in -5..70 {
+        let (x, y) = (a.contains(&t), b.contains(&t));
+        assert_eq!(a.union(&b).contains(&t), x || y);
+        assert_eq!(a.intersection(&b).contains(&t), x && y);
+        assert_eq!(a.difference(&b).contains(&t), x && !y);
+    }
+    let count =
+        |set: &IntervalSet<i32, Inclusive, Exclusive>| (-5..70).filter(|t| set.contains(t)).count();
+    assert_eq!(a.measure() as usize, count(&a));
+    assert_eq!(
+        a.difference(&b).measure() as usize,
+        count(&a.difference(&b))
+    );
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn uniform_sampling() {
+    use rand::{Rng, SeedableRng};
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+    let n = 10000;
+
+    let a = Exclusive.at(0.0).to(Exclusive.at(1.0));
+    let samples: Vec<f64> = (&mut rng).sample_iter(a.uniform()).take(n).collect();
+    assert!(samples.iter().all(|t| a.contains(t)));
+    let mean = samples.iter().sum::<f64>() / n as f64;
+    assert!((mean - 0.5).abs() < 0.02);
+
+    let b = Exclusive.at(0).to(Inclusive.at(4));
+    let mut counts = [0; 5];
+    for t in (&mut rng).sample_iter(b.uniform()).take(n) {
+        counts[t as usize] += 1;
+    }
+    assert_eq!(counts[0], 0);
+    assert!(counts[1..]
+        .iter()
+        .all(|&c| (c as f64 - n as f64 / 4.0).abs() < n as f64 * 0.05));
+
+    let c = Exclusive
+        .at(1.0f32)
+        .to(Exclusive.at(1.0f32 + 2.0 * f32::EPSILON));
+    assert!((0..100).all(|_| c.sample(&mut rng) == 1.0 + f32::EPSILON));
+}
+
+#[test]
+fn find_containing() {
+    let set: IntervalSet<i32, Inclusive, Exclusive> = (0..1000)
+        .map(|i| Inclusive.at(i * 10).to(Exclusive.at(i * 10 + 1 + i % 9)))
+        .collect();
+    assert_eq!(set.len(), 1000);
+    for t in -10..10010 {
+        let expected = set.iter().find(|item| item.contains(&t));
+        assert_eq!(set.find_containing(&t), expected);
+        assert_eq!(Interval::find_containing(&set, &t), expected);
+    }
+}
+
+#[test]
+fn dilate_one_side() {
+    let a = Inclusive.at(0).to(Exclusive.at(3)); // [0, 3)
+    for d in 0..5 {
+        let b = a.dilate_right(d).unwrap();
+        assert_eq!(b.left, a.left);
+        assert_eq!(b.sup(), &(3 + d));
+        assert!(!b.contains(&(3 + d)));
+        assert_eq!(a.dilate_asym(0, d), Some(b));
+    }
+    assert_eq!(a.dilate_right(-3), None);
+    assert_eq!(a.dilate_left(-2), Some(Inclusive.at(2).to(Exclusive.at(3))));
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn round_to_integers() {
+    let a = Inclusive.at(-1.5).to(Inclusive.at(2.5)); // [-1.5, 2.5]
+    assert_eq!(a.round_out::<i32>(), Some(Inclusive.between(-2, 3)));
+    assert_eq!(a.round_in::<i32>(), Some(Inclusive.between(-1, 2)));
+    assert_eq!(a.try_cast::<i32>(), Some(Inclusive.between(-1, 2)));
+
+    let b = Exclusive.at(-0.5).to(Exclusive.at(0.5)); // (-0.5, 0.5)
+    assert_eq!(b.round_out::<i64>(), Some(Exclusive.between(-1, 1)));
+    assert_eq!(b.round_in::<i64>(), Some(Inclusive.between(0, 0)));
+
+    let c = Inclusive.at(-2.0).to(Exclusive.at(3.0)); // [-2, 3)
+    assert_eq!(
+        c.round_out::<i8>(),
+        Some(Inclusive.at(-2).to(Exclusive.at(3)))
+    );
+    assert_eq!(c.round_in::<i8>(), Some(Inclusive.between(-2, 2)));
+    assert_eq!(Inclusive.between(0.0, 1e10).round_out::<i32>(), None);
+
+    // Exclusive endpoints keep the integers inside.
+    let d = Inclusive.at(0.5).to(Exclusive.at(2.5)); // [0.5, 2.5)
+    assert_eq!(d.round_in::<i32>(), Some(Inclusive.between(1, 2)));
+    let e = Exclusive.between(0.5, 2.5); // (0.5, 2.5)
+    assert_eq!(e.round_in::<i32>(), Some(Inclusive.between(1, 2)));
+    let f = Exclusive.between(0.0, 3.0); // (0, 3)
+    assert_eq!(f.round_in::<i32>(), Some(Inclusive.between(1, 2)));
+    let g = Exclusive.at(1.0).to(Inclusive.at(2.0)); // (1, 2]
+    assert_eq!(g.round_in::<i32>(), Some(Inclusive.between(2, 2)));
+    assert_eq!(Exclusive.between(1.0, 2.0).round_in::<i32>(), None);
+    assert_eq!(Exclusive.between(1.2, 1.8).round_in::<i32>(), None);
+}
+
+#[test]
+fn saturating_cast() {
+    let big = i32::MAX as i64 + 100;
+    let a = Inclusive.at(-big).to(Inclusive.at(big));
+    assert_eq!(a.try_cast::<i32>(), None);
+    assert_eq!(
+        a.saturating_cast::<i32>(),
+        Some(Inclusive.between(i32::MIN, i32::MAX))
+    );
+    let b = Exclusive.at(-big).to(Inclusive.at(5));
+    assert_eq!(
+        b.saturating_cast::<i32>(),
+        Some(Exclusive.at(i32::MIN).to(Inclusive.at(5)))
+    );
+    let c = Inclusive.at(5).to(Exclusive.at(big));
+    assert_eq!(
+        c.saturating_cast::<i32>(),
+        Some(Inclusive.at(5).to(Exclusive.at(i32::MAX)))
+    );
+    assert_eq!(
+        Inclusive.between(big, big + 1).saturating_cast::<i32>(),
+        Some(Inclusive.between(i32::MAX, i32::MAX))
+    );
+    assert_eq!(
+        Exclusive.between(big, big + 1).saturating_cast::<i32>(),
+        None
+    );
+
+    let d = Inclusive.between(-1e300, 1e300);
+    assert_eq!(
+        d.saturating_cast::<u16>(),
+        Some(Inclusive.between(0, u16::MAX))
+    );
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn scale_about_center() {
+    let a = Inclusive.between(0.0, 10.0);
+    assert_eq!(a.scale(0.5), Inclusive.between(2.5, 7.5));
+    assert_eq!(a.scale(1.0), a);
+    assert_eq!(a.scale(4.0).scale(0.25), a);
+    for factor in [0.1f64, 0.5, 1.5, 3.0] {
+        let b = a.scale(factor);
+        assert_eq!(b.center(), a.center());
+        assert!((b.measure() - factor * a.measure()).abs() < 1e-12);
+    }
+    let c = Exclusive.at(-2.0).to(Inclusive.at(2.0));
+    assert_eq!(c.scale(0.5), Exclusive.at(-1.0).to(Inclusive.at(1.0)));
+}
+
+#[test]
+fn overlap_measure() {
+    let a = Inclusive.at(0.0).to(Exclusive.at(3.0));
+    let others = [
+        Inclusive.at(1.0).to(Exclusive.at(4.0)),
+        Inclusive.at(-1.0).to(Exclusive.at(0.5)),
+        Inclusive.at(3.0).to(Exclusive.at(5.0)),   // touching
+        Inclusive.at(10.0).to(Exclusive.at(11.0)), // disjoint
+        a,
+    ];
+    for b in &others {
+        let expected = a.intersection(b).map_or(0.0, |i| i.measure());
+        assert_eq!(a.overlap_measure(b), expected);
+        assert_eq!(b.overlap_measure(&a), expected);
+    }
+    assert_eq!(a.overlap_measure(&others[3]), 0.0);
+
+    let b: Box2<f64> = Box2::between(&[0.0, 0.0], &[2.0, 2.0]);
+    let c: Box2<f64> = Box2::between(&[1.0, -1.0], &[3.0, 0.5]);
+    let d: Box2<f64> = Box2::between(&[1.0, 3.0], &[3.0, 4.0]);
+    assert_eq!(b.overlap_measure(&c), 0.5);
+    assert_eq!(b.overlap_measure(&d), 0.0);
+}
+
+#[cfg(feature = "proptest")]
+mod proptests {
+    use crate::{BoundType, Box2, Exclusive, GeneralInterval, Inclusive, Interval};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn intersection_is_commutative(
+            a in Interval::<i32, Inclusive, Exclusive>::arbitrary_in(-100..=100),
+            b in Interval::<i32, Inclusive, Exclusive>::arbitrary_in(-100..=100),
+        ) {
+            prop_assert_eq!(a.intersection(&b), b.intersection(&a));
+        }
+
+        #[test]
+        fn general_intersection_is_commutative(
+            a in GeneralInterval::<f64>::arbitrary_in(-1.0..=1.0),
+            b in GeneralInterval::<f64>::arbitrary_in(-1.0..=1.0),
+        ) {
+            prop_assert_eq!(a.intersection(&b), b.intersection(&a));
+        }
+
+        #[test]
+        fn never_empty(
+            a in Interval::<i32, Exclusive>::arbitrary_in(0..=3),
+            b in Interval::<f64, BoundType, Exclusive>::arbitrary_in(0.0..=1.0),
+            c in Box2::<i32, Exclusive, Inclusive>::arbitrary_in(0..=3),
+        ) {
+            prop_assert!(a.inf() < a.sup());
+            prop_assert!(b.inf() < b.sup());
+            prop_assert!(c.iter().all(|i| i.inf() < i.sup()));
+        }
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+mod quickchecks {
+    use crate::{Exclusive, Inclusive, Interval};
+    use quickcheck::{quickcheck, Arbitrary};
+
+    quickcheck! {
+        fn span_includes_both(a: Interval<i32>, b: Interval<i32>) -> bool {
+            a.span(&b).includes(&a) && a.span(&b).includes(&b)
+        }
+        fn float_span_includes_both(a: Interval<f64, Exclusive, Inclusive>, b: Interval<f64, Exclusive, Inclusive>) -> bool {
+            a.span(&b).includes(&a) && a.span(&b).includes(&b)
+        }
+    }
+
+    #[test]
+    fn shrink_toward_origin() {
+        let a = Inclusive.between(-7, 12);
+        let smallest = std::iter::successors(Some(a), |x| x.shrink().next()).last();
+        assert_eq!(smallest, Some(Inclusive.between(0, 0)));
+
+        let b = Inclusive.at(5).to(Exclusive.at(9));
+        assert!(b.shrink().all(|x| x.inf() < x.sup()));
+        let smallest = std::iter::successors(Some(b), |x| x.shrink().next()).last();
+        assert_eq!(smallest, Some(Inclusive.at(0).to(Exclusive.at(1))));
+    }
+}
+
+#[test]
+fn separation() {
+    let a = Inclusive.between(0.0, 3.0);
+    let cases = [
+        (Inclusive.between(1.0, 4.0), 0.0),   // overlapping
+        (Inclusive.between(-1.0, 5.0), 0.0),  // including
+        (Inclusive.between(3.0, 4.0), 0.0),   // touching
+        (Inclusive.between(5.5, 8.0), 2.5),   // separated above
+        (Inclusive.between(-4.0, -1.0), 1.0), // separated below
+    ];
+    for (b, expected) in cases {
+        assert_eq!(a.separation(&b), expected);
+        assert_eq!(b.separation(&a), expected);
+        assert_eq!(a.gap(&b).map_or(0.0, |gap| gap.measure()), expected);
+    }
+
+    let b = Inclusive.at(0).to(Exclusive.at(3));
+    let c = Inclusive.at(3).to(Exclusive.at(5)); // touching [0, 3) and [3, 5)
+    assert_eq!(b.separation(&c), 0);
+    assert!(b.gap(&c).is_none());
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn contains_within() {
+    let a = Exclusive.between(1.0, 2.0); // (1, 2)
+    let eps = 1e-9;
+    for t in [1.0, 1.0 - 1e-12, 2.0, 2.0 + 1e-12] {
+        assert!(!a.contains(&t));
+        assert!(a.contains_within(t, eps));
+    }
+    for t in [1.0 - 1e-6, 2.0 + 1e-6] {
+        assert!(!a.contains_within(t, eps));
+    }
+    assert!(a.contains_within(1.5, 0.0));
+    assert!(!a.contains_within(1.0 - 1e-12, 0.0));
+}
+
+#[test]
+fn negation() {
+    assert_eq!(-Inclusive.between(-2, 3), Inclusive.between(-3, 2));
+    assert_eq!(-Exclusive.between(-2, 3), Exclusive.between(-3, 2));
+    assert_eq!(
+        -Inclusive.at(-2).to(Exclusive.at(3)),
+        Exclusive.at(-3).to(Inclusive.at(2))
+    );
+    assert_eq!(
+        -Exclusive.at(-2).to(Inclusive.at(3)),
+        Inclusive.at(-3).to(Exclusive.at(2))
+    );
+    let a = BoundType::Exclusive
+        .at(0.5)
+        .to(BoundType::Inclusive.at(1.5));
+    assert_eq!(
+        -a,
+        BoundType::Inclusive
+            .at(-1.5)
+            .to(BoundType::Exclusive.at(-0.5))
+    );
+    let b = Inclusive.at(1).to(Exclusive.at(10));
+    assert!((-b).contains(&-1) && !(-b).contains(&-10));
+}
+
+#[test]
+fn integer_iteration() {
+    assert_eq!(Exclusive.between(0, 1).into_iter().count(), 0);
+    assert_eq!(Exclusive.at(0).to(Inclusive.at(1)).into_iter().count(), 1);
+    assert_eq!(Inclusive.between(0, 0).into_iter().count(), 1);
+    assert_eq!(Inclusive.between(0u32, 0).into_iter().count(), 1);
+    assert_eq!(Inclusive.at(0u8).to(Exclusive.at(1)).into_iter().count(), 1);
+    assert_eq!(
+        Inclusive
+            .between(i32::MAX, i32::MAX)
+            .into_iter()
+            .collect::<Vec<_>>(),
+        vec![i32::MAX]
+    );
+    assert_eq!(
+        Inclusive
+            .between(i64::MIN, i64::MIN)
+            .into_iter()
+            .collect::<Vec<_>>(),
+        vec![i64::MIN]
+    );
+    assert_eq!(Inclusive.between(u8::MIN, u8::MAX).into_iter().count(), 256);
+    assert_eq!(
+        Exclusive
+            .at(-1_000_000)
+            .to(Inclusive.at(1_000_000))
+            .into_iter()
+            .count(),
+        2_000_000
+    );
+}
+
+#[test]
+fn integer_iteration_both_ends() {
+    let cases: [GeneralInterval<i32>; 5] = [
+        Interval::from(Inclusive.at(-3).to(Inclusive.at(4))),
+        Interval::from(Inclusive.at(-3).to(Exclusive.at(4))),
+        Interval::from(Exclusive.at(-3).to(Exclusive.at(4))),
+        Interval::from(Exclusive.at(0).to(Exclusive.at(1))),
+        Interval::from(Inclusive.at(7).to(Inclusive.at(7))),
+    ];
+    for a in cases {
+        let forward: Vec<i32> = a.into_iter().collect();
+        let mut backward: Vec<i32> = a.into_iter().rev().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+        assert_eq!(
+            a.into_iter().size_hint(),
+            (forward.len(), Some(forward.len()))
+        );
+        assert!(forward.iter().all(|t| a.contains(t)));
+    }
+
+    let mut iter = Inclusive.between(1i16, 5).into_iter();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next_back(), Some(5));
+    assert_eq!(iter.len(), 3);
+    assert!(iter.clone().eq([2, 3, 4]));
+    assert_eq!(iter.next_back(), Some(4));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next_back(), Some(3));
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let all = Inclusive.between(i32::MIN, i32::MAX).into_iter();
+    assert_eq!(all.size_hint(), (1 << 32, Some(1 << 32)));
+    assert!(all.rev().take(2).eq([i32::MAX, i32::MAX - 1]));
+
+    assert_eq!(Inclusive.between(i8::MIN, i8::MAX).into_iter().len(), 256);
+    assert_eq!(Exclusive.between(0u16, 3).into_iter().len(), 2);
+
+    let top = Inclusive.between(u128::MAX - 1, u128::MAX).into_iter();
+    assert_eq!(top.size_hint(), (2, Some(2)));
+    assert_eq!(top.collect::<Vec<_>>(), vec![u128::MAX - 1, u128::MAX]);
+    let mut top = Inclusive.between(u128::MAX - 1, u128::MAX).into_iter();
+    assert_eq!(top.next_back(), Some(u128::MAX));
+    assert_eq!(top.size_hint(), (1, Some(1)));
+
+    let all = Inclusive.between(i64::MIN, i64::MAX).into_iter();
+    assert_eq!(all.size_hint(), (0, None));
+    assert!(all.clone().take(2).eq([i64::MIN, i64::MIN + 1]));
+    assert!(all.rev().take(2).eq([i64::MAX, i64::MAX - 1]));
+    let all = Inclusive.between(u128::MIN, u128::MAX).into_iter();
+    assert_eq!(all.size_hint(), (0, None));
+    assert!(all.take(3).eq([0, 1, 2]));
+}
+
+#[test]
+fn step_intervals() {
+    let a = Inclusive.at(3).to(Exclusive.at(20));
+    for step in 1..25 {
+        let chunks: Vec<_> = a.step_intervals(step).collect();
+        assert_eq!(chunks.first().unwrap().inf(), a.inf());
+        assert_eq!(chunks.last().unwrap().sup(), a.sup());
+        assert!(chunks.iter().all(|c| c.measure() <= step && a.includes(c)));
+        assert!(chunks.windows(2).all(|w| w[0].sup() == w[1].inf()));
+        assert_eq!(chunks.iter().map(|c| c.measure()).sum::<i32>(), a.measure());
+    }
+
+    let b = Inclusive.at(0.0).to(Exclusive.at(1.0));
+    let chunks: Vec<_> = b.step_intervals(0.25).collect();
+    assert_eq!(chunks.len(), 4);
+    assert!(chunks.windows(2).all(|w| w[0].sup() == w[1].inf()));
+    assert_eq!(chunks[3], Inclusive.at(0.75).to(Exclusive.at(1.0)));
+
+    let chunks: Vec<_> = b.step_intervals(0.3).collect();
+    assert_eq!(chunks.len(), 4);
+    assert_eq!(chunks[3].sup(), &1.0);
+    assert!(chunks.windows(2).all(|w| w[0].sup() == w[1].inf()));
+}
+
+#[test]
+fn count_integers() {
+    let cases: [(GeneralInterval<i32>, i32); 8] = [
+        (Inclusive.between(-3, 4).into(), 8),
+        (Inclusive.at(-3).to(Exclusive.at(4)).into(), 7),
+        (Exclusive.at(-3).to(Inclusive.at(4)).into(), 7),
+        (Exclusive.between(-3, 4).into(), 6),
+        (Exclusive.between(0, 1).into(), 0),
+        (Exclusive.at(0).to(Inclusive.at(1)).into(), 1),
+        (Inclusive.between(5, 5).into(), 1),
+        (Exclusive.at(0).to(Inclusive.at(10)).into(), 10),
+    ];
+    for (a, expected) in cases {
+        assert_eq!(a.count_integers(), expected);
+        assert_eq!(a.into_iter().count(), expected as usize);
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_measure() {
+    use chrono::{TimeDelta, TimeZone, Utc};
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap();
+    let a = Inclusive.at(start).to(Exclusive.at(end));
+    assert_eq!(a.measure(), TimeDelta::hours(1));
+    assert!(a.contains(&Utc.with_ymd_and_hms(2024, 1, 1, 9, 30, 0).unwrap()));
+    assert!(!a.contains(&end));
+
+    let b = Inclusive
+        .at(start + TimeDelta::minutes(45))
+        .to(Exclusive.at(end + TimeDelta::hours(1)));
+    assert_eq!(
+        a.intersection(&b).unwrap().measure(),
+        TimeDelta::minutes(15)
+    );
+    assert_eq!(a.span(&b).measure(), TimeDelta::hours(2));
+}
+
+#[test]
+fn nth_fraction() {
+    let a = Inclusive.between(0, 9);
+    assert_eq!(a.nth_fraction(0, 3), 0);
+    assert_eq!(a.nth_fraction(1, 3), 3);
+    assert_eq!(a.nth_fraction(2, 3), 6);
+    assert_eq!(a.nth_fraction(3, 3), 9);
+    assert_eq!(a.nth_fraction(4, 3), 12);
+
+    for den in 1..20u32 {
+        for num in 0..=den {
+            let b = Inclusive.between(-37i64, 1000);
+            let expected = (*b.inf() as f64 + b.measure() as f64 * num as f64 / den as f64 + 0.5)
+                .floor() as i64;
+            assert_eq!(b.nth_fraction(num, den), expected);
+        }
+    }
+
+    let c = Inclusive.between(0u8, 250);
+    assert_eq!(c.nth_fraction(1, 2), 125);
+    assert_eq!(c.nth_fraction(99, 100), 248); // 247.5
+}
+
+#[test]
+fn from_range_bounds() {
+    use std::ops::Bound;
+    type I = GeneralInterval<i32>;
+    let (incl, excl) = (BoundType::Inclusive, BoundType::Exclusive);
+    assert_eq!(I::from_range_bounds(2..5), Some(incl.at(2).to(excl.at(5))));
+    assert_eq!(I::from_range_bounds(2..=5), Some(incl.at(2).to(incl.at(5))));
+    assert_eq!(
+        I::from_range_bounds(2..),
+        Some(incl.at(2).to(incl.at(i32::MAX)))
+    );
+    assert_eq!(
+        I::from_range_bounds(..5),
+        Some(incl.at(i32::MIN).to(excl.at(5)))
+    );
+    assert_eq!(
+        I::from_range_bounds(..=5),
+        Some(incl.at(i32::MIN).to(incl.at(5)))
+    );
+    assert_eq!(
+        I::from_range_bounds((Bound::Excluded(2), Bound::Included(5))),
+        Some(excl.at(2).to(incl.at(5)))
+    );
+    assert_eq!(I::from_range_bounds(..), None);
+    assert_eq!(
+        I::from_range_bounds((Bound::Included(5), Bound::Excluded(2))),
+        None
+    );
+    assert_eq!(I::from_range_bounds(5..5), None);
+    assert_eq!(I::from_range_bounds(5..=5), Some(incl.at(5).to(incl.at(5))));
+
+    let a = GeneralInterval::from_range_bounds(0.5..).unwrap();
+    assert_eq!(a.sup(), &f64::MAX);
+}
+
+#[test]
+fn float_range_conversions() {
+    let a: Interval<f64, Inclusive, Exclusive> = (2.74..4.26).try_into().unwrap();
+    assert_eq!(a, Inclusive.at(2.74).to(Exclusive.at(4.26)));
+    assert!(a.contains(&2.74) && !a.contains(&4.26));
+
+    let b: Interval<f64> = (2.74..=4.26).try_into().unwrap();
+    assert_eq!(b, Inclusive.between(2.74, 4.26));
+    assert!(b.contains(&4.26));
+
+    assert!(Interval::<f64, Inclusive, Exclusive>::try_from(4.26..2.74).is_err());
+    assert!(Interval::<f64, Inclusive, Exclusive>::try_from(1.0..1.0).is_err());
+    assert!(Interval::<f64>::try_from(4.26..=2.74).is_err());
+    assert!(Interval::<f64>::try_from(f64::NAN..=1.0).is_err());
+    assert!(Interval::<f64, Inclusive, Exclusive>::try_from(1.0..f64::NAN).is_err());
+}
+
+#[test]
+fn contains_interior() {
+    let closed = Inclusive.between(0.0, 1.0);
+    let open = Exclusive.between(0.0, 1.0);
+    let half = Inclusive.at(0.0).to(Exclusive.at(1.0));
+    for t in [0.0, 1.0] {
+        assert!(closed.contains(&t));
+        assert!(!closed.contains_interior(&t));
+        assert!(!open.contains_interior(&t));
+        assert!(!half.contains_interior(&t));
+    }
+    for t in [1e-9, 0.5, 1.0 - 1e-9] {
+        assert!(closed.contains_interior(&t));
+        assert!(open.contains_interior(&t));
+        assert!(half.contains_interior(&t));
+    }
+    assert!(!closed.contains_interior(&-0.5));
+    assert!(!Inclusive.between(3, 3).contains_interior(&3));
+
+    let b: Box2<i32> = Box2::between(&[0, 0], &[4, 2]);
+    assert!(b.contains_interior(&[2, 1]));
+    for p in [[0, 1], [4, 1], [2, 0], [2, 2], [0, 0], [5, 1]] {
+        assert!(!b.contains_interior(&p));
+    }
+}
+
+#[test]
+fn enclosure() {
+    let items = [
+        Inclusive.at(5.0).to(Exclusive.at(7.0)),
+        Inclusive.at(0.0).to(Exclusive.at(2.0)),
+        Inclusive.at(1.0).to(Exclusive.at(3.0)),
+        Inclusive.at(12.0).to(Exclusive.at(13.0)),
+    ];
+    let enclosure = Interval::enclosure(items).unwrap();
+    assert_eq!(enclosure, Inclusive.at(0.0).to(Exclusive.at(13.0)));
+    assert_eq!(Some(enclosure), Interval::span_many(items));
+    assert!(items.iter().all(|item| enclosure.includes(item)));
+
+    let (incl, excl) = (BoundType::Inclusive, BoundType::Exclusive);
+    let mixed = [
+        excl.at(0).to(excl.at(5)),
+        incl.at(0).to(excl.at(1)),
+        incl.at(8).to(excl.at(10)),
+        excl.at(9).to(incl.at(10)),
+    ];
+    assert_eq!(Interval::enclosure(mixed), Some(incl.at(0).to(incl.at(10))));
+    assert_eq!(
+        Interval::enclosure(mixed.into_iter().take(3)),
+        Some(incl.at(0).to(excl.at(10)))
+    );
+
+    let boxes: [Box2<f64>; 2] = [
+        Box2::between(&[0.0, 1.0], &[1.0, 2.0]),
+        Box2::between(&[3.0, -1.0], &[4.0, 0.0]),
+    ];
+    assert_eq!(
+        Box2::enclosure(boxes),
+        Some(Box2::between(&[0.0, -1.0], &[4.0, 2.0]))
+    );
+    assert_eq!(Box2::<f64>::enclosure([]), None);
+}
+
+#[test]
+fn intersect_all() {
+    let items = [
+        Interval::between(0, 10),
+        Interval::between(5, 15),
+        Interval::between(8, 12),
+    ];
+    let product: Nullable<Interval<i32>> = items.into_iter().product();
+    assert_eq!(product.unwrap(), Interval::between(8, 10));
+    assert_eq!(Nullable::intersect_all(items.iter()), product);
+
+    let (a, b) = (
+        Inclusive.at(0.0).to(Exclusive.at(1.0)),
+        Inclusive.at(1.0).to(Exclusive.at(2.0)),
+    );
+    let disjoint: Nullable<Interval<f64, _, _>> = [a, b, a].into_iter().product();
+    assert!(disjoint.is_null());
+    assert!(!disjoint.contains(&1.0));
+    assert_eq!(disjoint.measure(), 0.0);
+}
+
+#[test]
+fn gaps() {
+    let items = [
+        Inclusive.at(0.0).to(Exclusive.at(1.0)),
+        Inclusive.at(0.5).to(Exclusive.at(2.0)),
+        Inclusive.at(3.0).to(Exclusive.at(4.0)),
+    ];
+    let gaps: Vec<_> = Interval::gaps(items).collect();
+    assert_eq!(gaps, vec![Inclusive.at(2.0).to(Exclusive.at(3.0))]);
+
+    let closed: [Interval<i32>; 4] = [
+        Interval::between(0, 2),
+        Interval::between(1, 5),
+        Interval::between(2, 3),
+        Interval::between(7, 9),
+    ];
+    let gaps: Vec<_> = Interval::gaps(closed).collect();
+    assert_eq!(gaps, vec![Exclusive.at(5).to(Exclusive.at(7))]);
+
+    assert_eq!(Interval::gaps([Interval::<i32>::between(0, 1)]).count(), 0);
+    assert_eq!(Interval::<i32>::gaps([]).count(), 0);
+}
+
+#[test]
+fn coverage() {
+    let target = Inclusive.at(0.0).to(Exclusive.at(10.0));
+    let complete = [
+        Inclusive.at(5.0).to(Exclusive.at(10.0)),
+        Inclusive.at(0.0).to(Exclusive.at(3.0)),
+        Inclusive.at(2.0).to(Exclusive.at(5.0)),
+    ];
+    assert!(target.is_covered_by(&complete));
+    assert!(target.uncovered_gaps(&complete).is_empty());
+
+    let hole = [
+        Inclusive.at(0.0).to(Exclusive.at(4.0)),
+        Inclusive.at(6.0).to(Exclusive.at(10.0)),
+    ];
+    assert!(!target.is_covered_by(&hole));
+    assert_eq!(
+        target.uncovered_gaps(&hole),
+        vec![Inclusive.at(4.0).to(Exclusive.at(6.0))]
+    );
+
+    let ends = [Inclusive.at(2.0).to(Exclusive.at(8.0))];
+    assert!(!target.is_covered_by(&ends));
+    assert_eq!(
+        target.uncovered_gaps(&ends),
+        vec![
+            Inclusive.at(0.0).to(Exclusive.at(2.0)),
+            Inclusive.at(8.0).to(Exclusive.at(10.0)),
+        ]
+    );
+
+    // Closed parts touching at a point still cover.
+    let closed: Interval<i32> = Interval::between(0, 10);
+    assert!(closed.is_covered_by(&[Interval::between(0, 5), Interval::between(5, 10)]));
+    assert!(!closed.is_covered_by(&[Interval::between(0, 4), Interval::between(5, 10)]));
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn coverage_ratio() {
+    let a = Inclusive.at(0.0).to(Exclusive.at(4.0));
+    assert_eq!(a.coverage_ratio(&a), 1.0);
+    assert_eq!(
+        a.coverage_ratio(&Inclusive.at(-1.0).to(Exclusive.at(5.0))),
+        1.0
+    );
+
+    let b = Inclusive.at(3.0).to(Exclusive.at(10.0));
+    assert_eq!(a.coverage_ratio(&b), 0.25);
+    assert_eq!(b.coverage_ratio(&a), 1.0 / 7.0);
+
+    let c = Inclusive.at(4.0).to(Exclusive.at(6.0));
+    assert_eq!(a.coverage_ratio(&c), 0.0);
+    assert_eq!(c.coverage_ratio(&a), 0.0);
+
+    let point: Interval<f64> = Interval::between(1.0, 1.0);
+    assert_eq!(point.coverage_ratio(&Interval::between(0.0, 2.0)), 1.0);
+    assert_eq!(point.coverage_ratio(&Interval::between(1.5, 2.0)), 0.0);
+}
+
+#[test]
+fn const_intervals() {
+    const GRADES: [(Interval<u32, Inclusive, Exclusive>, char); 3] = [
+        (
+            Interval::new_unchecked(Inclusive.at(80), Exclusive.at(101)),
+            'A',
+        ),
+        (
+            Interval::new_unchecked(Inclusive.at(60), Exclusive.at(80)),
+            'B',
+        ),
+        (
+            Interval::new_unchecked(Bound::new(0, Inclusive), Bound::new(60, Exclusive)),
+            'C',
+        ),
+    ];
+    static UNIT: Interval<f64> = Interval::new_unchecked(Inclusive.at(0.0), Inclusive.at(1.0));
+
+    let grade = |score: u32| {
+        GRADES
+            .iter()
+            .find(|(i, _)| i.contains(&score))
+            .map(|(_, g)| *g)
+    };
+    assert_eq!(grade(100), Some('A'));
+    assert_eq!(grade(80), Some('A'));
+    assert_eq!(grade(79), Some('B'));
+    assert_eq!(grade(0), Some('C'));
+    assert_eq!(grade(101), None);
+    for (interval, _) in GRADES {
+        assert_eq!(
+            Interval::try_new(**interval.left(), **interval.right()),
+            Some(interval)
+        );
+    }
+    assert_eq!(UNIT, Interval::between(0.0, 1.0));
+}
+
+#[test]
+fn new_unchecked() {
+    for (a, b) in [(0, 1), (-5, 3), (7, 8)] {
+        assert_eq!(
+            Interval::new_unchecked(Inclusive.at(a), Exclusive.at(b)),
+            Interval::new(Inclusive.at(a), Exclusive.at(b))
+        );
+        let (l, r) = (BoundType::Exclusive.at(a), BoundType::Inclusive.at(b));
+        assert_eq!(Interval::new_unchecked(l, r), Interval::new(l, r));
+    }
+    assert_eq!(
+        Interval::new_unchecked(Inclusive.at(2.5), Inclusive.at(2.5)),
+        Interval::between(2.5, 2.5)
+    );
+    let boxes: Box3<f64, Inclusive, Exclusive> =
+        Box3::between_unchecked(&[0.0, -1.0, 2.0], &[1.0, 0.0, 4.0]);
+    assert_eq!(boxes, Box3::between(&[0.0, -1.0, 2.0], &[1.0, 0.0, 4.0]));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Invalid interval")]
+fn between_unchecked_debug_assert() {
+    Box2::<i32, Inclusive, Exclusive>::between_unchecked(&[0, 3], &[1, 3]);
+}
+
+#[test]
+fn span_many_owned() {
+    // A non-Clone limit type can be spanned only by value.
+    #[derive(Debug, PartialEq, PartialOrd)]
+    struct Key(u64);
+    let items = (0..10u64).map(|i| {
+        Interval::new(
+            Inclusive.at(Key(i * 7 % 10)),
+            Exclusive.at(Key(i * 7 % 10 + 3)),
+        )
+    });
+    let span = Interval::span_many_owned(items).unwrap();
+    assert_eq!(span.inf(), &Key(0));
+    assert_eq!(span.sup(), &Key(12));
+
+    let (incl, excl) = (BoundType::Inclusive, BoundType::Exclusive);
+    let items: Vec<Interval<i32, BoundType>> = (0..20)
+        .map(|i| {
+            let (a, b) = (i * 13 % 17, i * 13 % 17 + i % 4 + 1);
+            let l = if i % 2 == 0 { incl } else { excl };
+            let r = if i % 3 == 0 { incl } else { excl };
+            Interval::new(l.at(a), r.at(b))
+        })
+        .collect();
+    assert_eq!(
+        Interval::span_many_owned(items.clone()),
+        Interval::span_many(&items)
+    );
+    assert_eq!(Interval::<i32>::span_many_owned([]), None);
+}
+
+#[test]
+fn contains_batch() {
+    let points: Vec<f64> = (-4..=24).map(|i| i as f64 * 0.5).collect();
+    let (incl, excl) = (BoundType::Inclusive, BoundType::Exclusive);
+    for l in [incl, excl] {
+        for r in [incl, excl] {
+            let a = Interval::new(l.at(0.0), r.at(10.0));
+            let expected: Vec<_> = points.iter().filter(|t| a.contains(t)).collect();
+            assert_eq!(a.filter_contained(&points).collect::<Vec<_>>(), expected);
+            assert_eq!(
+                a.contains_all(&points),
+                points.iter().all(|t| a.contains(t))
+            );
+            assert!(a.contains_all(&expected.into_iter().copied().collect::<Vec<_>>()));
+        }
+    }
+
+    let a = Inclusive.at(0.0).to(Exclusive.at(1.0));
+    assert!(!a.contains_all(&[0.5, f64::NAN]));
+    assert_eq!(a.filter_contained(&[f64::NAN, 0.0, 1.0]).count(), 1);
+}
+
+#[test]
+fn interval_batch() {
+    let (incl, excl) = (BoundType::Inclusive, BoundType::Exclusive);
+    let items: Vec<Interval<i32, BoundType>> = (0..200)
+        .map(|i| {
+            let a = i * 37 % 101;
+            let l = if i % 2 == 0 { incl } else { excl };
+            let r = if i % 3 == 0 { incl } else { excl };
+            Interval::new(l.at(a), r.at(a + i % 13 + 1))
+        })
+        .collect();
+    let batch: IntervalBatch<_, _, _> = items.iter().cloned().collect();
+    assert_eq!(batch.len(), items.len());
+    assert_eq!(batch.iter().collect::<Vec<_>>(), items);
+    assert_eq!(batch.get(items.len()), None);
+
+    for t in -2..120 {
+        let expected: Vec<_> = (0..items.len())
+            .filter(|&i| items[i].contains(&t))
+            .collect();
+        assert_eq!(batch.query_contains(&t), expected);
+    }
+    for query in items.iter().take(30) {
+        let expected: Vec<_> = (0..items.len())
+            .filter(|&i| items[i].overlaps(query))
+            .collect();
+        assert_eq!(batch.query_overlaps(query), expected);
+    }
+
+    let empty = IntervalBatch::<f64>::new();
+    assert!(empty.is_empty());
+    assert!(empty.query_contains(&0.0).is_empty());
+}
+
+#[test]
+fn to_static() {
+    let (incl, excl) = (BoundType::Inclusive, BoundType::Exclusive);
+    let points = [-1, 0, 1, 9, 10, 11];
+    let contained = |i: &StaticInterval<i32>| points.map(|t| i.contains(&t));
+
+    let a = incl.at(0).to(incl.at(10)).to_static();
+    assert_eq!(a, StaticInterval::Closed(Inclusive.between(0, 10)));
+    assert_eq!(contained(&a), [false, true, true, true, true, false]);
+
+    let a = excl.at(0).to(excl.at(10)).to_static();
+    assert_eq!(a, StaticInterval::Open(Exclusive.between(0, 10)));
+    assert_eq!(contained(&a), [false, false, true, true, false, false]);
+
+    let a = incl.at(0).to(excl.at(10)).to_static();
+    assert_eq!(
+        a,
+        StaticInterval::RightHalfOpen(Inclusive.at(0).to(Exclusive.at(10)))
+    );
+    assert_eq!(contained(&a), [false, true, true, true, false, false]);
+
+    let a = excl.at(0).to(incl.at(10)).to_static();
+    assert_eq!(
+        a,
+        StaticInterval::LeftHalfOpen(Exclusive.at(0).to(Inclusive.at(10)))
+    );
+    assert_eq!(contained(&a), [false, false, true, true, true, false]);
+
+    for (l, r) in [(incl, incl), (incl, excl), (excl, incl), (excl, excl)] {
+        let general = l.at(0).to(r.at(10));
+        assert_eq!(Interval::from(general.to_static()), general);
+        for t in points {
+            assert_eq!(general.to_static().contains(&t), general.contains(&t));
+        }
+    }
+}
+
+#[test]
+fn int_interval_set() {
+    let mut a = IntIntervalSet::new();
+    a.insert_interval(Inclusive.between(0, 5));
+    a.insert_interval(Inclusive.between(10, 12));
+    assert_eq!(
+        a.iter_intervals().collect::<Vec<_>>(),
+        vec![Inclusive.between(0, 5), Inclusive.between(10, 12)]
+    );
+    assert!(a.contains(&5) && !a.contains(&6) && a.contains(&10));
+    assert_eq!(a.len(), 9);
+
+    // Compare with IntervalSet across word boundaries and negative values.
+    let items: Vec<Interval<i64, Inclusive, Exclusive>> = (0..60)
+        .map(|i: i64| {
+            let begin = i * 97 % 400 - 200;
+            Inclusive.at(begin).to(Exclusive.at(begin + i % 9 + 1))
+        })
+        .collect();
+    let bits: IntIntervalSet<i64> = items.iter().cloned().collect();
+    let set: IntervalSet<_, _, _> = items.iter().cloned().collect();
+    for t in -210..210 {
+        assert_eq!(bits.contains(&t), set.contains(&t), "t = {t}");
+    }
+    let runs: Vec<_> = bits.iter_intervals().collect();
+    let expected: Vec<_> = set
+        .iter()
+        .map(|i| Inclusive.between(*i.inf(), *i.sup() - 1))
+        .collect();
+    assert_eq!(runs, expected);
+
+    let mut b = IntIntervalSet::<u8>::new();
+    assert!(b.is_empty() && b.iter_intervals().next().is_none());
+    b.insert_interval(Exclusive.between(0, 1));
+    assert!(b.is_empty());
+    b.insert(255);
+    b.insert(0);
+    assert_eq!(
+        b.iter_intervals().collect::<Vec<_>>(),
+        vec![Inclusive.between(0, 0), Inclusive.between(255, 255)]
+    );
+
+    let mut c = IntIntervalSet::<u128>::new();
+    let max = i128::MAX as u128;
+    c.insert_interval(Inclusive.between(max - 3, max));
+    assert!(c.contains(&max) && !c.contains(&(max + 1)));
+    assert_eq!(c.len(), 4);
+}
+
+#[test]
+#[should_panic]
+fn int_interval_set_beyond_i128() {
+    IntIntervalSet::<u128>::new().insert(i128::MAX as u128 + 1);
+}
+
+#[test]
+fn difference_general() {
+    let (incl, excl) = (BoundType::Inclusive, BoundType::Exclusive);
+    let a: Interval<i32> = Interval::between(0, 10);
+
+    let diff = a.difference_general(&Interval::between(3, 5));
+    assert_eq!(diff.lower, Some(incl.at(0).to(excl.at(3))));
+    assert_eq!(diff.upper, Some(excl.at(5).to(incl.at(10))));
+
+    let diff = a.difference_general(&Interval::between(5, 15));
+    assert_eq!(diff.into_vec(), vec![incl.at(0).to(excl.at(5))]);
+    assert!(a
+        .difference_general(&Interval::between(-1, 11))
+        .into_vec()
+        .is_empty());
+    assert_eq!(
+        a.difference_general(&Interval::between(10, 12)).into_vec(),
+        vec![incl.at(0).to(excl.at(10))]
+    );
+
+    let open = Exclusive.between(0.0, 1.0);
+    let diff = open.difference_general(&Exclusive.between(0.5, 2.0));
+    assert_eq!(diff.into_vec(), vec![excl.at(0.0).to(incl.at(0.5))]);
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn lattice_invalid_spacing() {
+    let a = Inclusive.at(0.0).to(Exclusive.at(10.0));
+    for spacing in [0.0, -1.0, -0.5, f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert_eq!(a.lattice_count(1.0, spacing), 0, "spacing = {spacing}");
+        assert_eq!(
+            a.lattice_points(1.0, spacing).count(),
+            0,
+            "spacing = {spacing}"
+        );
+    }
+    assert_eq!(a.lattice_count(f64::NAN, 1.0), 0);
+
+    // The indices of the lattice points don't fit in i64.
+    let huge = Inclusive.at(0.0).to(Inclusive.at(1e30));
+    assert_eq!(huge.lattice_count(0.0, 1.0), 0);
+    assert_eq!(huge.lattice_points(0.0, 1.0).next(), None);
+    assert_eq!(a.lattice_count(0.0, 1e-300), 0);
+
+    // Still fine close to the limits.
+    assert_eq!(a.lattice_count(0.0, 1e-10), 100_000_000_000);
+    assert_eq!(a.lattice_count(0.0, 2.5), 4);
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn step_for_count_few_samples() {
+    let a = Inclusive.at(0.0).to(Inclusive.at(10.0));
+    assert_eq!(a.step_for_count(0, false), 10.0);
+    assert_eq!(a.step_for_count(0, true), 10.0);
+    assert_eq!(a.step_for_count(1, true), 10.0);
+    assert_eq!(a.step_for_count(1, false), 10.0);
+    assert_eq!(a.step_for_count(2, true), 10.0);
+    assert_eq!(a.step_for_count(2, false), 5.0);
+    assert_eq!(a.step_by(a.step_for_count(1, false)).count(), 2);
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn normalizer_degenerate() {
+    let a = Inclusive.at(2.0).to(Inclusive.at(2.0));
+    let normalize = a.normalizer();
+    assert_eq!(normalize(2.0), 0.0);
+    assert_eq!(normalize(3.0), a.inverse_lerp(3.0));
 }
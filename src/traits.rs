@@ -1,4 +1,4 @@
-use crate::BoundType;
+use crate::{BoundType, Interval};
 
 pub trait Flip {
     type Flip: Flip<Flip = Self>;
@@ -13,15 +13,30 @@ pub(crate) trait IntoGeneral {
 pub trait Boundary: Flip + Eq + PartialEq<BoundType> + Copy {
     fn less<T: PartialOrd>(&self, this: &T, t: &T) -> bool;
 
+    /// Inclusive only if both `self` and `other` are inclusive.
+    fn combine(&self, other: &Self) -> Self;
+
     fn is_inclusive(&self) -> bool {
         *self == BoundType::Inclusive
     }
     fn is_exclusive(&self) -> bool {
         *self == BoundType::Exclusive
     }
+    fn to_bound_type(&self) -> BoundType {
+        if self.is_inclusive() {
+            BoundType::Inclusive
+        } else {
+            BoundType::Exclusive
+        }
+    }
 }
 
 pub trait BoundaryOf<LR>: Boundary {
     type Ordered: Ord;
     fn into_ordered(self) -> Self::Ordered;
 }
+
+/// Point `T` or `Interval<T, L, R>` accepted by `Interval::contains_any()`.
+pub trait Containee<T, L, R> {
+    fn is_contained_in(&self, interval: &Interval<T, L, R>) -> bool;
+}
@@ -0,0 +1,49 @@
+//! The core API used from a `no_std` crate.
+//! Run `cargo test --no-default-features --test no_std` to build the library without `std` as well.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec;
+use inter_val::{Bound, BoundType, Box2, Exclusive, Inclusive, Interval, IntervalSet, Nullable};
+
+#[test]
+fn interval() {
+    let a = Inclusive.at(0).to(Exclusive.at(10));
+    let b = Inclusive.at(5).to(Exclusive.at(15));
+    assert!(a.contains(&0) && !a.contains(&10));
+    assert_eq!(
+        a.intersection(&b),
+        Some(Inclusive.at(5).to(Exclusive.at(10)))
+    );
+    assert_eq!(a.span(&b), Inclusive.at(0).to(Exclusive.at(15)));
+    assert_eq!(
+        a.difference(&b).into_vec(),
+        vec![Inclusive.at(0).to(Exclusive.at(5))]
+    );
+    assert_eq!(a.measure(), 10);
+
+    const UNIT: Interval<i32> =
+        Interval::new_unchecked(Bound::new(0, Inclusive), Bound::new(1, Inclusive));
+    assert_eq!(UNIT, Inclusive.between(0, 1));
+
+    let general = BoundType::Exclusive.at(0).to(BoundType::Inclusive.at(3));
+    assert!(general.contains(&3) && !general.contains(&0));
+}
+
+#[test]
+fn collections() {
+    let set: IntervalSet<i32, Inclusive, Exclusive> = [
+        Inclusive.at(0).to(Exclusive.at(3)),
+        Inclusive.at(3).to(Exclusive.at(5)),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(set.into_vec(), vec![Inclusive.at(0).to(Exclusive.at(5))]);
+
+    let hull: Nullable<Interval<i32>> = [3, 1, 4].into_iter().sum();
+    assert_eq!(hull.unwrap(), Inclusive.between(1, 4));
+
+    let a: Box2<i32> = Box2::between(&[0, 0], &[2, 3]);
+    assert_eq!(a.measure(), 6);
+}